@@ -0,0 +1,85 @@
+//! Absurdle: an adversarial game where the answer is chosen only once it can no longer be avoided
+
+use std::collections::HashMap;
+
+use crate::index::WordIndex;
+use crate::solver::{decode_feedback, feedback_code};
+use crate::{normalize_guess_word, Colour, Constraints, WordleQueryError};
+
+// Struct for an adversarial game over the words of an index
+#[derive(Debug, Clone)]
+pub struct Absurdle {
+    index: WordIndex,
+    constraints: Constraints,
+    candidates: Vec<String>,
+    rows: Vec<(String, Vec<Colour>)>,
+}
+
+impl Absurdle {
+    /// Start with every word of the index still possible
+    pub fn new(index: WordIndex) -> Self {
+        let constraints = Constraints::new(index.length());
+        let candidates = index.words().to_vec();
+        Absurdle { index, constraints, candidates, rows: Vec::new() }
+    }
+
+    /// Play a guess. Of all the colourings the game could show, it picks the one that keeps the
+    /// most candidates alive, preferring fewer greens and then fewer yellows when that is a tie.
+    /// Any remaining tie goes to the lowest feedback code, so the game is deterministic.
+    pub fn guess(&mut self, word: &str) -> Result<Vec<Colour>, WordleQueryError> {
+        if self.is_solved() {
+            return Err(WordleQueryError::InvalidGuess("The game is over.".to_string()));
+        }
+        let word = normalize_guess_word(word);
+        if word.chars().count() != self.index.length() {
+            return Err(WordleQueryError::InvalidGuess(format!("The word must have {} letters.", self.index.length())));
+        }
+        if self.index.words().binary_search(&word).is_err() {
+            return Err(WordleQueryError::InvalidGuess(format!("'{}' is not in the dictionary.", word)));
+        }
+
+        let letters: Vec<char> = word.chars().collect();
+        let mut buckets: HashMap<u32, usize> = HashMap::new();
+        for candidate in &self.candidates {
+            *buckets.entry(feedback_code(&letters, &candidate.chars().collect::<Vec<_>>())).or_insert(0) += 1;
+        }
+        let colours = buckets
+            .into_iter()
+            .map(|(code, size)| (size, code, decode_feedback(code, letters.len())))
+            .max_by(|(a_size, a_code, a), (b_size, b_code, b)| {
+                a_size.cmp(b_size).then_with(|| revealed(b).cmp(&revealed(a))).then(b_code.cmp(a_code))
+            })
+            .map(|(_, _, colours)| colours)
+            .unwrap_or_else(|| vec![Colour::Grey; letters.len()]);
+
+        // Narrow the candidates the same way a solver would, through the constraints
+        self.constraints.add_guess(&word, &colours)?;
+        self.candidates = self.index.filter(&self.constraints).into_iter().map(str::to_string).collect();
+        self.rows.push((word, colours.clone()));
+        Ok(colours)
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.rows.last().is_some_and(|(_, colours)| colours.iter().all(|&c| c == Colour::Green))
+    }
+
+    /// Words that are still consistent with every row
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    pub fn rows(&self) -> &[(String, Vec<Colour>)] {
+        &self.rows
+    }
+
+    pub fn length(&self) -> usize {
+        self.index.length()
+    }
+}
+
+/// How much a colouring gives away, greens counting before yellows
+fn revealed(colours: &[Colour]) -> (usize, usize) {
+    let greens = colours.iter().filter(|&&c| c == Colour::Green).count();
+    let yellows = colours.iter().filter(|&&c| c == Colour::Yellow).count();
+    (greens, yellows)
+}
@@ -0,0 +1,118 @@
+//! Post-game analysis comparing each guess with the best guess by expected information
+
+use crate::index::WordIndex;
+use crate::solver::{feedback_colours, rank_by_information, GuessRanking};
+use crate::{normalize_guess_word, Colour, Constraints, WordleQueryError};
+
+// Struct for the analysis of one turn
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnAnalysis {
+    pub guess: String,
+    pub colours: Vec<Colour>,
+    /// Candidates still possible before the guess
+    pub candidates_before: usize,
+    /// Candidates the guess actually left
+    pub remaining: usize,
+    /// Expected information of the guess in bits
+    pub bits: f64,
+    /// Average number of candidates the guess would leave
+    pub expected_remaining: f64,
+    /// Guess with the most expected information at this turn
+    pub best_guess: String,
+    pub best_bits: f64,
+    pub best_expected_remaining: f64,
+    /// Candidates the best guess would have left against this answer
+    pub best_remaining: usize,
+    /// Information of the guess as a percentage of the best guess's, from 0 to 100
+    pub skill: f64,
+}
+
+// Struct for the analysis of a whole game
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameAnalysis {
+    pub answer: String,
+    pub turns: Vec<TurnAnalysis>,
+}
+
+impl GameAnalysis {
+    pub fn solved(&self) -> bool {
+        self.turns.last().is_some_and(|turn| turn.guess == self.answer)
+    }
+
+    /// Average skill over the turns that had a choice to make
+    pub fn skill(&self) -> f64 {
+        let scored: Vec<f64> = self.turns.iter().filter(|t| t.candidates_before > 1).map(|t| t.skill).collect();
+        if scored.is_empty() {
+            return 100.0;
+        }
+        scored.iter().sum::<f64>() / scored.len() as f64
+    }
+}
+
+/// Replay a finished game against the dictionary in the index. Guesses after the answer is found are ignored.
+pub fn analyse_game(index: &WordIndex, guesses: &[String], answer: &str) -> Result<GameAnalysis, WordleQueryError> {
+    let answer = normalize_guess_word(answer);
+    let answer_chars: Vec<char> = answer.chars().collect();
+    if answer_chars.len() != index.length() {
+        return Err(WordleQueryError::QueryError(format!("The answer must have {} letters.", index.length())));
+    }
+
+    let mut constraints = Constraints::new(index.length());
+    let mut turns = Vec::new();
+    for guess in guesses {
+        let guess = normalize_guess_word(guess);
+        let guess_chars: Vec<char> = guess.chars().collect();
+        if guess_chars.len() != answer_chars.len() {
+            return Err(WordleQueryError::InvalidGuess(format!("'{}' must have {} letters.", guess, index.length())));
+        }
+
+        let candidates: Vec<String> = index.filter(&constraints).into_iter().map(str::to_string).collect();
+        let candidate_chars: Vec<Vec<char>> = candidates.iter().map(|w| w.chars().collect()).collect();
+        let pool = if candidates.len() > 2 { index.words() } else { &candidates[..] };
+        let best = rank_by_information(pool, &candidates).into_iter().next();
+        // Playing the best guess reuses its ranking, so it scores exactly full skill
+        let chosen = match &best {
+            Some(best) if best.guess == guess => best.clone(),
+            _ => GuessRanking::evaluate(&guess, &candidate_chars, candidates.contains(&guess)),
+        };
+        let best = best.unwrap_or_else(|| chosen.clone());
+
+        let colours = feedback_colours(&guess_chars, &answer_chars);
+        let remaining = remaining_after(index, &constraints, &guess, &colours)?;
+        let best_colours = feedback_colours(&best.guess.chars().collect::<Vec<_>>(), &answer_chars);
+        let best_remaining = remaining_after(index, &constraints, &best.guess, &best_colours)?;
+        let skill = if best.bits > 0.0 && chosen.guess != best.guess {
+            (100.0 * chosen.bits / best.bits).min(100.0)
+        } else {
+            100.0
+        };
+
+        constraints.add_guess(&guess, &colours)?;
+        let solved = guess == answer;
+        turns.push(TurnAnalysis {
+            guess,
+            colours,
+            candidates_before: candidates.len(),
+            remaining,
+            bits: chosen.bits,
+            expected_remaining: chosen.expected_remaining,
+            best_guess: best.guess,
+            best_bits: best.bits,
+            best_expected_remaining: best.expected_remaining,
+            best_remaining,
+            skill,
+        });
+        if solved {
+            break;
+        }
+    }
+
+    Ok(GameAnalysis { answer, turns })
+}
+
+/// Candidates left after adding one more guess row to the constraints
+fn remaining_after(index: &WordIndex, constraints: &Constraints, guess: &str, colours: &[Colour]) -> Result<usize, WordleQueryError> {
+    let mut next = constraints.clone();
+    next.add_guess(guess, colours)?;
+    Ok(index.filter(&next).len())
+}
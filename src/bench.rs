@@ -0,0 +1,130 @@
+//! Simulated games for measuring how well a strategy solves a list of answers
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::index::WordIndex;
+use crate::scoring::Scorer;
+use crate::solver::{best_guess, encode_feedback, feedback_colours};
+use crate::{Constraints, Strategy, WordleQueryError};
+
+/// Guesses allowed by the real game
+pub const MAX_GUESSES: usize = 6;
+
+/// Turns after which a simulated game is abandoned, so a bad strategy cannot loop forever
+const TURN_LIMIT: usize = 20;
+
+// Struct for one simulated game
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameResult {
+    pub answer: String,
+    /// Every guess played, ending with the answer when solved
+    pub guesses: Vec<String>,
+    pub solved: bool,
+}
+
+impl GameResult {
+    pub fn guess_count(&self) -> usize {
+        self.guesses.len()
+    }
+}
+
+// Struct for the outcome of playing a whole answer list
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub results: Vec<GameResult>,
+    pub max_guesses: usize,
+}
+
+impl BenchReport {
+    /// Number of solved games for each guess count
+    pub fn distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution = BTreeMap::new();
+        for result in self.results.iter().filter(|r| r.solved) {
+            *distribution.entry(result.guess_count()).or_insert(0) += 1;
+        }
+        distribution
+    }
+
+    /// Games that were not solved within `max_guesses`
+    pub fn failures(&self) -> usize {
+        self.results.iter().filter(|r| !r.solved || r.guess_count() > self.max_guesses).count()
+    }
+
+    /// Mean number of guesses over the solved games
+    pub fn average_guesses(&self) -> f64 {
+        let solved: Vec<usize> = self.results.iter().filter(|r| r.solved).map(GameResult::guess_count).collect();
+        if solved.is_empty() {
+            return 0.0;
+        }
+        solved.iter().sum::<usize>() as f64 / solved.len() as f64
+    }
+
+    /// The games that took the most guesses, unsolved games first
+    pub fn hardest(&self, count: usize) -> Vec<&GameResult> {
+        let mut results: Vec<&GameResult> = self.results.iter().collect();
+        results.sort_by(|a, b| {
+            a.solved
+                .cmp(&b.solved)
+                .then(b.guess_count().cmp(&a.guess_count()))
+                .then(a.answer.cmp(&b.answer))
+        });
+        results.truncate(count);
+        results
+    }
+}
+
+// Struct for a solver that plays games against the words of an index
+pub struct Simulator<'a> {
+    index: &'a WordIndex,
+    strategy: Strategy,
+    scorer: &'a dyn Scorer,
+    /// Guess chosen after each sequence of feedback codes; the solver is deterministic,
+    /// so games that have seen the same feedback so far share their next guess
+    choices: HashMap<Vec<u32>, String>,
+}
+
+impl<'a> Simulator<'a> {
+    pub fn new(index: &'a WordIndex, strategy: Strategy, scorer: &'a dyn Scorer) -> Self {
+        Simulator { index, strategy, scorer, choices: HashMap::new() }
+    }
+
+    /// Play one game, feeding back the exact colours for `answer` after every guess
+    pub fn play(&mut self, answer: &str) -> Result<GameResult, WordleQueryError> {
+        let answer_chars: Vec<char> = answer.chars().collect();
+        let mut constraints = Constraints::new(self.index.length());
+        let mut history = Vec::new();
+        let mut guesses = Vec::new();
+
+        while guesses.len() < TURN_LIMIT {
+            let Some(guess) = self.next_guess(&history, &constraints) else { break };
+            let guess_chars: Vec<char> = guess.chars().collect();
+            let colours = feedback_colours(&guess_chars, &answer_chars);
+            guesses.push(guess.clone());
+            if guess == answer {
+                return Ok(GameResult { answer: answer.to_string(), guesses, solved: true });
+            }
+
+            constraints.add_guess(&guess, &colours)?;
+            history.push(encode_feedback(&colours)?);
+        }
+        Ok(GameResult { answer: answer.to_string(), guesses, solved: false })
+    }
+
+    /// Play every answer and collect the results
+    pub fn run<'w>(&mut self, answers: impl IntoIterator<Item = &'w str>, max_guesses: usize) -> Result<BenchReport, WordleQueryError> {
+        let results = answers.into_iter().map(|answer| self.play(answer)).collect::<Result<_, _>>()?;
+        Ok(BenchReport { results, max_guesses })
+    }
+
+    fn next_guess(&mut self, history: &[u32], constraints: &Constraints) -> Option<String> {
+        if let Some(guess) = self.choices.get(history) {
+            return Some(guess.clone());
+        }
+
+        let candidates: Vec<String> = self.index.filter(constraints).into_iter().map(str::to_string).collect();
+        let guess = best_guess(self.strategy, self.scorer, self.index.words(), &candidates)?;
+
+        self.choices.insert(history.to_vec(), guess.clone());
+        Some(guess)
+    }
+}
@@ -0,0 +1,166 @@
+//! Daily puzzles: the same secret word for everyone on the same date, salt and dictionary
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::WordleQueryError;
+
+/// Date of puzzle number 1
+pub const DAILY_EPOCH: PuzzleDate = PuzzleDate { year: 2024, month: 1, day: 1 };
+
+/// Days within which an answer is not used again, unless the dictionary is too small for that
+pub const DEFAULT_WINDOW: usize = 365;
+
+/// Hashed picks tried before falling back to the next unused word in dictionary order
+const MAX_PICKS: u64 = 64;
+
+// Struct for a calendar date in the proleptic Gregorian calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl PuzzleDate {
+    /// Today's date in UTC
+    pub fn today() -> Self {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+        PuzzleDate::from_days(seconds as i64 / 86_400)
+    }
+
+    /// Days since 1970-01-01
+    pub fn days(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date a number of days after 1970-01-01
+    pub fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        PuzzleDate { year, month, day }
+    }
+
+    /// Puzzle number for this date, counting `DAILY_EPOCH` as number 1
+    pub fn puzzle_number(&self) -> Result<u64, WordleQueryError> {
+        let offset = self.days() - DAILY_EPOCH.days();
+        if offset < 0 {
+            return Err(WordleQueryError::QueryError(format!(
+                "There are no daily puzzles before {}.",
+                DAILY_EPOCH
+            )));
+        }
+        Ok(offset as u64 + 1)
+    }
+}
+
+impl FromStr for PuzzleDate {
+    type Err = WordleQueryError;
+
+    /// Parse a date written as `YYYY-MM-DD`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || WordleQueryError::QueryError(format!("Date '{}' must look like YYYY-MM-DD.", text));
+        let mut parts = text.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let year: i32 = next()?.parse().map_err(|_| invalid())?;
+        let month: u32 = next()?.parse().map_err(|_| invalid())?;
+        let day: u32 = next()?.parse().map_err(|_| invalid())?;
+
+        let date = PuzzleDate { year, month, day };
+        // Out-of-range days such as 02-30 do not survive the round trip
+        if !(1..=12).contains(&month) || day == 0 || PuzzleDate::from_days(date.days()) != date {
+            return Err(invalid());
+        }
+        Ok(date)
+    }
+}
+
+impl fmt::Display for PuzzleDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// Struct for choosing the answer of each day from a dictionary
+#[derive(Debug, Clone)]
+pub struct DailyPuzzle {
+    words: Vec<String>,
+    salt: String,
+    window: usize,
+}
+
+impl DailyPuzzle {
+    /// Sort the words so the answers depend only on the vocabulary, not on how it was loaded
+    pub fn new(words: impl IntoIterator<Item = String>, salt: &str, window: usize) -> Self {
+        let mut words: Vec<String> = words.into_iter().collect::<HashSet<_>>().into_iter().collect();
+        words.sort();
+        DailyPuzzle { words, salt: salt.to_string(), window }
+    }
+
+    /// Answer for the given date. Each day's answer is checked against the answers of the
+    /// `window` days before it, so every day from `DAILY_EPOCH` onwards is replayed.
+    pub fn answer(&self, date: PuzzleDate) -> Result<&str, WordleQueryError> {
+        let number = date.puzzle_number()?;
+        if self.words.is_empty() {
+            return Err(WordleQueryError::QueryError("The dictionary is empty.".to_string()));
+        }
+        let window = self.window.min(self.words.len() - 1);
+
+        let mut recent: VecDeque<usize> = VecDeque::with_capacity(window + 1);
+        let mut used: HashSet<usize> = HashSet::new();
+        let mut answer = 0;
+        for day in 1..=number {
+            answer = self.pick(day, &used);
+            if window > 0 {
+                recent.push_back(answer);
+                used.insert(answer);
+                if recent.len() > window {
+                    if let Some(expired) = recent.pop_front() {
+                        used.remove(&expired);
+                    }
+                }
+            }
+        }
+        Ok(&self.words[answer])
+    }
+
+    /// Hash the salt, day and attempt until the pick is not among the recent answers
+    fn pick(&self, day: u64, used: &HashSet<usize>) -> usize {
+        let count = self.words.len();
+        let mut index = 0;
+        for attempt in 0..MAX_PICKS {
+            index = (stable_hash(&self.salt, day, attempt) % count as u64) as usize;
+            if !used.contains(&index) {
+                return index;
+            }
+        }
+        (0..count).map(|offset| (index + offset) % count).find(|i| !used.contains(i)).unwrap_or(index)
+    }
+}
+
+/// FNV-1a over the salt, day and attempt. Unlike the standard hasher it gives the same
+/// result on every platform and compiler version, which keeps the daily answers stable.
+fn stable_hash(salt: &str, day: u64, attempt: u64) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let bytes = salt.bytes().chain([0]).chain(day.to_le_bytes()).chain(attempt.to_le_bytes());
+    for byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
@@ -0,0 +1,260 @@
+//! Building the SQLite word database from plain-text, CSV or TSV word lists
+
+use rusqlite::{params, Connection};
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::path::Path;
+
+use crate::{Wordle, WordleQueryError};
+
+/// Schema the query layer relies on: one lowercase word per row, with optional metadata
+pub const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS words (
+    word TEXT PRIMARY KEY NOT NULL,
+    frequency REAL,
+    pos TEXT
+);
+CREATE INDEX IF NOT EXISTS words_length ON words (LENGTH(word));";
+
+// Enum for the supported word list layouts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictFormat {
+    /// One word per line
+    Plain,
+    /// Comma-separated `word,frequency,pos`
+    Csv,
+    /// Tab-separated `word<TAB>frequency<TAB>pos`
+    Tsv,
+}
+
+impl DictFormat {
+    /// Guess the format from a file extension, defaulting to one word per line
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()).map(str::to_lowercase).as_deref() {
+            Some("csv") => DictFormat::Csv,
+            Some("tsv") | Some("tab") => DictFormat::Tsv,
+            _ => DictFormat::Plain,
+        }
+    }
+}
+
+impl std::str::FromStr for DictFormat {
+    type Err = WordleQueryError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "plain" | "txt" => Ok(DictFormat::Plain),
+            "csv" => Ok(DictFormat::Csv),
+            "tsv" => Ok(DictFormat::Tsv),
+            _ => Err(WordleQueryError::ImportError(format!(
+                "Unknown word list format '{}', expected plain, csv or tsv.",
+                name
+            ))),
+        }
+    }
+}
+
+// Struct for one word read from a word list
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictEntry {
+    pub word: String,
+    pub frequency: Option<f64>,
+    pub pos: Option<String>,
+}
+
+// Struct for the outcome of an import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportStats {
+    /// Rows read from the word list
+    pub read: usize,
+    /// Rows dropped because the word was not purely Cyrillic
+    pub skipped: usize,
+    /// Rows that repeated a word already seen
+    pub duplicates: usize,
+    /// Distinct words written to the database
+    pub imported: usize,
+}
+
+/// Lowercase a word and replace `ё`, returning `None` if it is not made of Russian letters only
+pub fn normalize_word(word: &str) -> Option<String> {
+    let word = Wordle::replace_yo(&word.trim().to_lowercase());
+    let is_russian = !word.is_empty() && word.chars().all(|c| ('а'..='я').contains(&c));
+    is_russian.then_some(word)
+}
+
+/// Read a word list. CSV and TSV files may start with a header naming the `word`,
+/// `frequency` and `pos` columns; without one the columns are taken in that order.
+pub fn read_word_list(reader: impl BufRead, format: DictFormat) -> Result<Vec<DictEntry>, WordleQueryError> {
+    let delimiter = match format {
+        DictFormat::Plain => return read_plain(reader),
+        DictFormat::Csv => b',',
+        DictFormat::Tsv => b'\t',
+    };
+
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .comment(Some(b'#'))
+        .from_reader(reader);
+
+    let mut columns = (0, Some(1), Some(2));
+    let mut entries = Vec::new();
+    for (i, record) in csv_reader.records().enumerate() {
+        let record = record.map_err(|e| WordleQueryError::ImportError(e.to_string()))?;
+        if i == 0 {
+            if let Some(header) = header_columns(&record) {
+                columns = header;
+                continue;
+            }
+        }
+
+        let field = |column: Option<usize>| {
+            column.and_then(|c| record.get(c)).map(str::trim).filter(|value| !value.is_empty())
+        };
+        let Some(word) = field(Some(columns.0)) else { continue };
+        entries.push(DictEntry {
+            word: word.to_string(),
+            frequency: field(columns.1).and_then(|value| value.parse().ok()),
+            pos: field(columns.2).map(str::to_string),
+        });
+    }
+    Ok(entries)
+}
+
+fn read_plain(reader: impl BufRead) -> Result<Vec<DictEntry>, WordleQueryError> {
+    let mut entries = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        let word = line.trim();
+        if word.is_empty() || word.starts_with('#') {
+            continue;
+        }
+        entries.push(DictEntry { word: word.to_string(), frequency: None, pos: None });
+    }
+    Ok(entries)
+}
+
+/// Column indexes for word, frequency and part of speech if the record is a header row
+fn header_columns(record: &csv::StringRecord) -> Option<(usize, Option<usize>, Option<usize>)> {
+    let find = |names: &[&str]| {
+        record.iter().position(|field| names.contains(&field.trim().to_lowercase().as_str()))
+    };
+    let word = find(&["word", "lemma"])?;
+    let frequency = find(&["frequency", "freq", "count"]);
+    let pos = find(&["pos", "part_of_speech", "tag"]);
+    Some((word, frequency, pos))
+}
+
+/// Create the schema if needed and write the entries, merging repeated words.
+/// A repeated word keeps its highest frequency and its first part of speech.
+pub fn import_entries(conn: &mut Connection, entries: Vec<DictEntry>) -> Result<ImportStats, WordleQueryError> {
+    let mut stats = ImportStats { read: entries.len(), ..ImportStats::default() };
+
+    let mut merged: BTreeMap<String, DictEntry> = BTreeMap::new();
+    for entry in entries {
+        let Some(word) = normalize_word(&entry.word) else {
+            stats.skipped += 1;
+            continue;
+        };
+        match merged.get_mut(&word) {
+            Some(existing) => {
+                stats.duplicates += 1;
+                existing.frequency = match (existing.frequency, entry.frequency) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    (a, b) => a.or(b),
+                };
+                if existing.pos.is_none() {
+                    existing.pos = entry.pos;
+                }
+            }
+            None => {
+                merged.insert(word.clone(), DictEntry { word, ..entry });
+            }
+        }
+    }
+
+    migrate_legacy_table(conn)?;
+    conn.execute_batch(SCHEMA)?;
+    let tx = conn.transaction()?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT INTO words (word, frequency, pos) VALUES (?1, ?2, ?3)
+             ON CONFLICT(word) DO UPDATE SET
+                 frequency = COALESCE(MAX(excluded.frequency, words.frequency), excluded.frequency, words.frequency),
+                 pos = COALESCE(words.pos, excluded.pos)",
+        )?;
+        for entry in merged.values() {
+            stmt.execute(params![entry.word, entry.frequency, entry.pos])?;
+        }
+    }
+    tx.commit()?;
+
+    stats.imported = merged.len();
+    Ok(stats)
+}
+
+/// Bring a `words` table made before `dict import` existed up to `SCHEMA` in place. Missing
+/// metadata columns are added, words are normalized like imported ones and a unique index on
+/// `word` takes the place of the primary key, so other columns and views on the table are kept.
+fn migrate_legacy_table(conn: &mut Connection) -> Result<(), WordleQueryError> {
+    let columns: Vec<(String, bool)> = conn
+        .prepare("SELECT name, pk FROM pragma_table_info('words')")?
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? > 0)))?
+        .collect::<Result<_, _>>()?;
+    let has = |name: &str| columns.iter().any(|(column, _)| column == name);
+    let word_is_key = columns.iter().any(|(column, pk)| column == "word" && *pk);
+    let word_is_indexed = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type = 'index' AND name = 'words_word'")?
+        .exists([])?;
+    if columns.is_empty() || ((word_is_key || word_is_indexed) && has("frequency") && has("pos")) {
+        return Ok(());
+    }
+    if !has("word") {
+        return Err(WordleQueryError::ImportError(
+            "The existing `words` table has no `word` column; import into a new database instead.".to_string(),
+        ));
+    }
+
+    let words: Vec<String> = conn
+        .prepare("SELECT word FROM words WHERE word IS NOT NULL")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    // Words that are not plain Russian are left as they are rather than deleted
+    let mut renamed: BTreeMap<String, String> = BTreeMap::new();
+    let mut seen: BTreeMap<String, String> = BTreeMap::new();
+    for word in words {
+        let normalized = normalize_word(&word).unwrap_or_else(|| word.clone());
+        if let Some(other) = seen.insert(normalized.clone(), word.clone()) {
+            return Err(WordleQueryError::ImportError(format!(
+                "The existing `words` table has both '{}' and '{}', which are the same word '{}'; \
+                 remove one of them or import into a new database instead.",
+                other, word, normalized
+            )));
+        }
+        if normalized != word {
+            renamed.insert(word, normalized);
+        }
+    }
+
+    let tx = conn.transaction()?;
+    for (column, kind) in [("frequency", "REAL"), ("pos", "TEXT")] {
+        if !has(column) {
+            tx.execute(&format!("ALTER TABLE words ADD COLUMN {} {}", column, kind), [])?;
+        }
+    }
+    {
+        let mut stmt = tx.prepare("UPDATE words SET word = ?1 WHERE word = ?2")?;
+        for (word, normalized) in &renamed {
+            stmt.execute(params![normalized, word])?;
+        }
+    }
+    if !word_is_key {
+        tx.execute("CREATE UNIQUE INDEX IF NOT EXISTS words_word ON words (word)", [])?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Five-letter word list compiled into the binary
+#[cfg(feature = "embedded-words")]
+pub const EMBEDDED_WORDS: &str = include_str!("../data/words_ru5.txt");
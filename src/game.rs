@@ -0,0 +1,167 @@
+//! A playable game: a secret word, validated guesses, hard mode and keyboard letter states
+
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::solver::feedback_colours;
+use crate::{normalize_guess_word, Colour, WordleQueryError};
+
+/// Guesses allowed by default, as in the original game
+pub const DEFAULT_ATTEMPTS: usize = 6;
+
+/// Rows of the Russian ЙЦУКЕН keyboard, without `ё` since it is played as `е`
+pub const KEYBOARD_ROWS: [&str; 3] = ["йцукенгшщзхъ", "фывапролджэ", "ячсмитьбю"];
+
+// Enum for the state of a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+// Struct for a game in progress
+#[derive(Debug, Clone)]
+pub struct Game {
+    answer: String,
+    dictionary: HashSet<String>,
+    max_attempts: usize,
+    hard_mode: bool,
+    rows: Vec<(String, Vec<Colour>)>,
+}
+
+impl Game {
+    /// Start a game; the answer is always accepted as a guess even if it is missing from the dictionary
+    pub fn new<I, S>(answer: &str, dictionary: I, max_attempts: usize, hard_mode: bool) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let answer = normalize_guess_word(answer);
+        let mut dictionary: HashSet<String> = dictionary.into_iter().map(Into::into).collect();
+        dictionary.insert(answer.clone());
+        Game { answer, dictionary, max_attempts, hard_mode, rows: Vec::new() }
+    }
+
+    /// Play a guess and return its colours. Invalid guesses are rejected without using up an attempt.
+    pub fn guess(&mut self, word: &str) -> Result<Vec<Colour>, WordleQueryError> {
+        if self.status() != GameStatus::InProgress {
+            return Err(WordleQueryError::InvalidGuess("The game is over.".to_string()));
+        }
+
+        let word = normalize_guess_word(word);
+        let length = self.length();
+        if word.chars().count() != length {
+            return Err(WordleQueryError::InvalidGuess(format!("The word must have {} letters.", length)));
+        }
+        if !self.dictionary.contains(&word) {
+            return Err(WordleQueryError::InvalidGuess(format!("'{}' is not in the dictionary.", word)));
+        }
+        if self.hard_mode {
+            self.check_hard_mode(&word)?;
+        }
+
+        let letters: Vec<char> = word.chars().collect();
+        let answer: Vec<char> = self.answer.chars().collect();
+        let colours = feedback_colours(&letters, &answer);
+        self.rows.push((word, colours.clone()));
+        Ok(colours)
+    }
+
+    /// In hard mode every green must stay in place and every revealed letter must be reused
+    fn check_hard_mode(&self, word: &str) -> Result<(), WordleQueryError> {
+        let letters: Vec<char> = word.chars().collect();
+        for (guess, colours) in &self.rows {
+            let mut revealed: HashMap<char, usize> = HashMap::new();
+            for (i, (c, colour)) in guess.chars().zip(colours).enumerate() {
+                if *colour == Colour::Green && letters[i] != c {
+                    return Err(WordleQueryError::InvalidGuess(format!(
+                        "Letter {} must be {}.",
+                        i + 1,
+                        c.to_uppercase()
+                    )));
+                }
+                if *colour != Colour::Grey {
+                    *revealed.entry(c).or_insert(0) += 1;
+                }
+            }
+            for (c, count) in revealed {
+                if letters.iter().filter(|&&l| l == c).count() < count {
+                    return Err(WordleQueryError::InvalidGuess(format!(
+                        "The guess must contain {}.",
+                        c.to_uppercase()
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn status(&self) -> GameStatus {
+        match self.rows.last() {
+            Some((_, colours)) if colours.iter().all(|&c| c == Colour::Green) => GameStatus::Won,
+            _ if self.rows.len() >= self.max_attempts => GameStatus::Lost,
+            _ => GameStatus::InProgress,
+        }
+    }
+
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    pub fn length(&self) -> usize {
+        self.answer.chars().count()
+    }
+
+    pub fn rows(&self) -> &[(String, Vec<Colour>)] {
+        &self.rows
+    }
+
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    pub fn attempts_left(&self) -> usize {
+        self.max_attempts.saturating_sub(self.rows.len())
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Best colour seen so far for a letter, or `None` if it has not been played
+    pub fn letter_state(&self, c: char) -> Option<Colour> {
+        letter_state(&self.rows, c)
+    }
+}
+
+/// Best colour a letter has shown in any row: green, then yellow, then grey
+pub fn letter_state(rows: &[(String, Vec<Colour>)], c: char) -> Option<Colour> {
+    rows.iter()
+        .flat_map(|(guess, colours)| guess.chars().zip(colours.iter().copied()))
+        .filter(|&(letter, _)| letter == c)
+        .map(|(_, colour)| colour)
+        .max_by_key(|&colour| rank(colour))
+}
+
+/// Order in which a key's colour can improve: grey, then yellow, then green
+fn rank(colour: Colour) -> u8 {
+    match colour {
+        Colour::Grey => 0,
+        Colour::Yellow => 1,
+        Colour::Green => 2,
+    }
+}
+
+/// Pick a random word for a new game, or `None` if there are no words
+pub fn random_word(words: &[String]) -> Option<&String> {
+    if words.is_empty() {
+        return None;
+    }
+    // The standard library has no RNG, but its hasher keys are randomly seeded per process
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or_default();
+    let seed = RandomState::new().hash_one(nanos);
+    words.get((seed % words.len() as u64) as usize)
+}
@@ -0,0 +1,166 @@
+//! Graduated hints that nudge towards the answer without listing the candidates
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::scoring::{PositionalFrequencies, Scorer};
+use crate::solver::best_guess;
+use crate::source::WordSource;
+use crate::{Constraints, Strategy, WordleQueryError};
+
+// Enum for the kinds of hint, from the one that gives away least to the one that gives away most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HintKind {
+    /// How many candidates are left
+    Count,
+    /// The most likely letter at a position that is not green yet
+    Letter,
+    /// Whether the answer repeats a letter
+    Repeats,
+    /// Parts of speech of the candidates
+    PartOfSpeech,
+    /// One suggested guess
+    Guess,
+}
+
+impl HintKind {
+    /// Every hint in the order they are revealed
+    pub const ALL: [HintKind; 5] =
+        [HintKind::Count, HintKind::Letter, HintKind::Repeats, HintKind::PartOfSpeech, HintKind::Guess];
+
+    /// The hint revealed after this one, or `None` after the suggested guess
+    pub fn next(self) -> Option<HintKind> {
+        HintKind::ALL.get(self as usize + 1).copied()
+    }
+}
+
+impl FromStr for HintKind {
+    type Err = WordleQueryError;
+
+    /// Accept a hint name or its number in the sequence, starting from 1
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "count" | "1" => Ok(HintKind::Count),
+            "letter" | "2" => Ok(HintKind::Letter),
+            "repeat" | "repeats" | "3" => Ok(HintKind::Repeats),
+            "pos" | "part-of-speech" | "4" => Ok(HintKind::PartOfSpeech),
+            "guess" | "5" => Ok(HintKind::Guess),
+            _ => Err(WordleQueryError::QueryError(format!(
+                "Unknown hint '{}'. Use count, letter, repeat, pos or guess.",
+                s
+            ))),
+        }
+    }
+}
+
+// Struct for the most likely letter at an unsolved position
+#[derive(Debug, Clone, PartialEq)]
+pub struct LetterHint {
+    /// Position in the word, from 0
+    pub position: usize,
+    pub letter: char,
+    /// Percentage of candidates with the letter at that position
+    pub share: f64,
+}
+
+// Enum for a hint worked out from the remaining candidates
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hint {
+    Count(usize),
+    /// `None` when every position is already green
+    Letter(Option<LetterHint>),
+    Repeats { repeated: usize, total: usize },
+    /// Candidates per part of speech out of `total`; words without one are left out of `counts`
+    PartOfSpeech { counts: BTreeMap<String, usize>, total: usize },
+    Guess(Option<String>),
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::Count(1) => write!(f, "1 candidate remains"),
+            Hint::Count(count) => write!(f, "{} candidates remain", count),
+            Hint::Letter(None) => write!(f, "Every position is already known"),
+            Hint::Letter(Some(hint)) => write!(
+                f,
+                "Letter {} is most likely {} ({:.0}% of candidates)",
+                hint.position + 1,
+                hint.letter.to_uppercase(),
+                hint.share
+            ),
+            Hint::Repeats { total: 0, .. } => write!(f, "No candidates remain"),
+            Hint::Repeats { repeated: 0, .. } => write!(f, "No candidate repeats a letter"),
+            Hint::Repeats { repeated, total } if repeated == total => write!(f, "Every candidate repeats a letter"),
+            Hint::Repeats { repeated, total } => write!(f, "{} of {} candidates repeat a letter", repeated, total),
+            Hint::PartOfSpeech { total: 0, .. } => write!(f, "No candidates remain"),
+            Hint::PartOfSpeech { counts, .. } if counts.is_empty() => write!(f, "The part of speech is unknown"),
+            Hint::PartOfSpeech { counts, total } if counts.len() == 1 && counts.values().sum::<usize>() == *total => {
+                write!(f, "Every candidate is a {}", counts.keys().next().unwrap())
+            }
+            Hint::PartOfSpeech { counts, .. } => {
+                let parts: Vec<String> = counts.iter().map(|(pos, count)| format!("{} {}", pos, count)).collect();
+                write!(f, "Parts of speech: {}", parts.join(", "))
+            }
+            Hint::Guess(None) => write!(f, "No guess to suggest"),
+            Hint::Guess(Some(word)) => write!(f, "Try {}", word.to_uppercase()),
+        }
+    }
+}
+
+/// The most likely letter among the positions that are not green yet, picking the position where
+/// one letter is most common. Ties go to the earlier position.
+pub fn likely_letter(constraints: &Constraints, candidates: &[String]) -> Option<LetterHint> {
+    let freqs = PositionalFrequencies::from_words(candidates.iter().map(String::as_str), constraints.length());
+    (0..constraints.length())
+        .filter(|&position| constraints.greens[position].is_none())
+        .filter_map(|position| freqs.most_common(position).map(|(letter, share)| LetterHint { position, letter, share }))
+        .max_by(|a, b| a.share.total_cmp(&b.share).then(b.position.cmp(&a.position)))
+}
+
+/// Number of candidates with at least one letter more than once
+pub fn repeated_letters(candidates: &[String]) -> usize {
+    candidates
+        .iter()
+        .filter(|word| {
+            let mut letters: Vec<char> = word.chars().collect();
+            let count = letters.len();
+            letters.sort_unstable();
+            letters.dedup();
+            letters.len() < count
+        })
+        .count()
+}
+
+/// Count the candidates by the part of speech the source records for them, ignoring the case of the tags
+pub fn parts_of_speech(candidates: &[String], source: &dyn WordSource) -> Result<BTreeMap<String, usize>, WordleQueryError> {
+    let mut counts = BTreeMap::new();
+    for word in candidates {
+        if let Some(pos) = source.part_of_speech(word)? {
+            *counts.entry(pos.to_lowercase()).or_insert(0) += 1;
+        }
+    }
+    Ok(counts)
+}
+
+/// Work out one hint. `source` supplies the parts of speech; the suggested guess is the solver's
+/// next guess for the strategy, taken from `dictionary`.
+pub fn give_hint(
+    kind: HintKind,
+    constraints: &Constraints,
+    candidates: &[String],
+    dictionary: &[String],
+    strategy: Strategy,
+    scorer: &dyn Scorer,
+    source: &dyn WordSource,
+) -> Result<Hint, WordleQueryError> {
+    Ok(match kind {
+        HintKind::Count => Hint::Count(candidates.len()),
+        HintKind::Letter => Hint::Letter(likely_letter(constraints, candidates)),
+        HintKind::Repeats => Hint::Repeats { repeated: repeated_letters(candidates), total: candidates.len() },
+        HintKind::PartOfSpeech => {
+            Hint::PartOfSpeech { counts: parts_of_speech(candidates, source)?, total: candidates.len() }
+        }
+        HintKind::Guess => Hint::Guess(best_guess(strategy, scorer, dictionary, candidates)),
+    })
+}
@@ -0,0 +1,195 @@
+//! In-memory index of one word length for filtering candidates without SQL
+
+use std::collections::HashSet;
+
+use crate::dict::normalize_word;
+use crate::source::WordSource;
+use crate::{Constraints, WordleQueryError};
+
+/// Number of letters from `а` to `я`
+const ALPHABET: usize = 32;
+
+/// Position of a letter in the alphabet, or `None` if it is not a lowercase Russian letter
+fn letter_index(c: char) -> Option<usize> {
+    ('а'..='я').contains(&c).then(|| c as usize - 'а' as usize)
+}
+
+// Struct for a fixed-size set of word indexes
+#[derive(Debug, Clone)]
+struct Bitset {
+    blocks: Vec<u64>,
+}
+
+impl Bitset {
+    fn empty(len: usize) -> Self {
+        Bitset { blocks: vec![0; len.div_ceil(64)] }
+    }
+
+    fn full(len: usize) -> Self {
+        let mut set = Bitset::empty(len);
+        for i in 0..len {
+            set.insert(i);
+        }
+        set
+    }
+
+    fn insert(&mut self, i: usize) {
+        self.blocks[i / 64] |= 1 << (i % 64);
+    }
+
+    fn intersect(&mut self, other: &Bitset) {
+        for (a, b) in self.blocks.iter_mut().zip(&other.blocks) {
+            *a &= b;
+        }
+    }
+
+    fn subtract(&mut self, other: &Bitset) {
+        for (a, b) in self.blocks.iter_mut().zip(&other.blocks) {
+            *a &= !b;
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.blocks.iter().enumerate().flat_map(|(block, &bits)| {
+            (0..64).filter(move |bit| bits & (1 << bit) != 0).map(move |bit| block * 64 + bit)
+        })
+    }
+}
+
+// Struct for the index: per-position letter bitsets over the words and a letter mask per word
+#[derive(Debug, Clone)]
+pub struct WordIndex {
+    length: usize,
+    words: Vec<String>,
+    /// Alphabet positions of every word's letters, `length` entries per word
+    codes: Vec<u8>,
+    /// Bit `i` is set when the word contains the `i`-th letter of the alphabet
+    masks: Vec<u32>,
+    /// `positions[pos * ALPHABET + letter]` holds the words with that letter at that position
+    positions: Vec<Bitset>,
+}
+
+impl WordIndex {
+    /// Index the words of the given length, normalizing them and dropping anything that is not Russian
+    pub fn new<I, S>(words: I, length: usize) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut words: Vec<String> = words
+            .into_iter()
+            .filter_map(|word| normalize_word(word.as_ref()))
+            .filter(|word| word.chars().count() == length)
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+        words.sort();
+
+        let mut codes = Vec::with_capacity(words.len() * length);
+        let mut masks = Vec::with_capacity(words.len());
+        let mut positions = vec![Bitset::empty(words.len()); length * ALPHABET];
+        for (i, word) in words.iter().enumerate() {
+            let mut mask = 0u32;
+            for (pos, c) in word.chars().enumerate() {
+                // normalize_word only lets through letters from а to я
+                let letter = letter_index(c).unwrap_or_default();
+                codes.push(letter as u8);
+                mask |= 1 << letter;
+                positions[pos * ALPHABET + letter].insert(i);
+            }
+            masks.push(mask);
+        }
+
+        WordIndex { length, words, codes, masks, positions }
+    }
+
+    /// Load every word of the given length from a word source into an index
+    pub fn from_source(source: &dyn WordSource, length: usize) -> Result<Self, WordleQueryError> {
+        Ok(WordIndex::new(source.words_of_length(length)?, length))
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Words matching the constraints in alphabetical order, evaluated in a single pass over the index
+    pub fn filter(&self, constraints: &Constraints) -> Vec<&str> {
+        if constraints.length() != self.length {
+            return Vec::new();
+        }
+
+        let mut selected = Bitset::full(self.words.len());
+        for (pos, green) in constraints.greens.iter().enumerate() {
+            if let Some(c) = green {
+                match letter_index(*c) {
+                    Some(letter) => selected.intersect(&self.positions[pos * ALPHABET + letter]),
+                    None => return Vec::new(),
+                }
+            }
+        }
+        for (pos, letters) in constraints.misplaced.iter().enumerate() {
+            for letter in letters.iter().filter_map(|&c| letter_index(c)) {
+                selected.subtract(&self.positions[pos * ALPHABET + letter]);
+            }
+        }
+
+        let (absent, max_counts) = constraints.absent_and_caps();
+        let mut required_mask = 0u32;
+        for &c in constraints.required.iter().chain(constraints.min_counts.keys()) {
+            if constraints.min_counts.get(&c) == Some(&0) {
+                continue;
+            }
+            match letter_index(c) {
+                Some(letter) => required_mask |= 1 << letter,
+                None => return Vec::new(),
+            }
+        }
+        let absent_mask = absent.iter().filter_map(|&c| letter_index(c)).fold(0u32, |mask, letter| mask | 1 << letter);
+
+        // Only counts that a letter mask cannot express need the word's letters
+        let mut bounds: Vec<(u8, usize, usize)> = Vec::new();
+        for (&c, &min) in constraints.min_counts.iter().filter(|(_, &min)| min > 1) {
+            let Some(letter) = letter_index(c) else { return Vec::new() };
+            bounds.push((letter as u8, min, usize::MAX));
+        }
+        for (&c, &max) in &max_counts {
+            if let Some(letter) = letter_index(c) {
+                bounds.push((letter as u8, 0, max));
+            }
+        }
+
+        selected
+            .iter()
+            .filter(|&i| {
+                let mask = self.masks[i];
+                if mask & required_mask != required_mask || mask & absent_mask != 0 {
+                    return false;
+                }
+                let codes = &self.codes[i * self.length..(i + 1) * self.length];
+                bounds.iter().all(|&(letter, min, max)| {
+                    let count = codes.iter().filter(|&&code| code == letter).count();
+                    (min..=max).contains(&count)
+                })
+            })
+            .map(|i| self.words[i].as_str())
+            .collect()
+    }
+}
+
+impl WordSource for WordIndex {
+    fn candidates(&self, constraints: &Constraints) -> Result<HashSet<String>, WordleQueryError> {
+        Ok(self.filter(constraints).into_iter().map(str::to_string).collect())
+    }
+}
@@ -0,0 +1,111 @@
+//! Correction of text typed with the keyboard still on the English QWERTY layout
+
+use crate::split_guess_row;
+
+/// The Cyrillic letter on the ЙЦУКЕН key that produces `c` on the QWERTY layout, keeping its case.
+/// Characters on other keys, including the `/`, `*` and `_` used in patterns, are returned unchanged.
+pub fn qwerty_to_jcuken(c: char) -> char {
+    match c {
+        'q' => 'й', 'w' => 'ц', 'e' => 'у', 'r' => 'к', 't' => 'е', 'y' => 'н',
+        'u' => 'г', 'i' => 'ш', 'o' => 'щ', 'p' => 'з', '[' => 'х', ']' => 'ъ',
+        'a' => 'ф', 's' => 'ы', 'd' => 'в', 'f' => 'а', 'g' => 'п', 'h' => 'р',
+        'j' => 'о', 'k' => 'л', 'l' => 'д', ';' => 'ж', '\'' => 'э',
+        'z' => 'я', 'x' => 'ч', 'c' => 'с', 'v' => 'м', 'b' => 'и', 'n' => 'т',
+        'm' => 'ь', ',' => 'б', '.' => 'ю', '`' => 'ё',
+        '{' => 'Х', '}' => 'Ъ', ':' => 'Ж', '"' => 'Э', '<' => 'Б', '>' => 'Ю', '~' => 'Ё',
+        _ if c.is_ascii_uppercase() => qwerty_to_jcuken(c.to_ascii_lowercase()).to_uppercase().next().unwrap(),
+        _ => c,
+    }
+}
+
+/// Text looks typed on the wrong layout when it has no Cyrillic letters but has a key for one.
+/// Text whose only such keys are `e` and `o` is left to `convert_latin_to_cyrillic`,
+/// since those are more likely look-alikes of `е` and `о` than the keys for `у` and `щ`.
+pub fn is_mistyped(text: &str) -> bool {
+    let cyrillic = text.chars().any(|c| matches!(c, 'а'..='я' | 'А'..='Я' | 'ё' | 'Ё'));
+    let keys = text
+        .chars()
+        .any(|c| !matches!(c.to_ascii_lowercase(), 'e' | 'o') && qwerty_to_jcuken(c) != c);
+    !cyrillic && keys
+}
+
+/// Retype a word or pattern entered on the wrong layout, leaving it unchanged otherwise
+pub fn fix_layout(text: &str) -> String {
+    if !is_mistyped(text) {
+        return text.to_string();
+    }
+    text.chars().map(qwerty_to_jcuken).collect()
+}
+
+/// Retype a reject list. A `,` between two other keys is kept as a separator; any other `,`,
+/// such as one on its own or at the end, is the key for `б`.
+pub fn fix_rejects(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let separators: Vec<bool> = (0..chars.len())
+        .map(|i| chars[i] == ',' && i > 0 && chars[i - 1] != ',' && chars.get(i + 1).is_some_and(|&c| c != ','))
+        .collect();
+    let keys: String = chars.iter().zip(&separators).filter(|(_, &separator)| !separator).map(|(&c, _)| c).collect();
+    if !is_mistyped(&keys) {
+        return text.to_string();
+    }
+    chars.iter().zip(&separators).map(|(&c, &separator)| if separator { c } else { qwerty_to_jcuken(c) }).collect()
+}
+
+/// Retype feedback entered as з/ж/с on the wrong layout, i.e. with the `p`, `;` and `c` keys.
+/// G/Y/B feedback uses other keys and is left alone.
+pub fn fix_feedback(text: &str) -> String {
+    if text.is_empty() || !text.chars().all(|c| matches!(c.to_ascii_lowercase(), 'p' | ';' | ':' | 'c')) {
+        return text.to_string();
+    }
+    text.chars().map(|c| qwerty_to_jcuken(c).to_lowercase().next().unwrap()).collect()
+}
+
+/// Retype a guess row such as `cjkyw:p;ccp`: the word and each `/`-separated feedback part are
+/// checked on their own, so Latin G/Y/B feedback does not stop the word from being converted
+pub fn fix_guess_row(row: &str) -> String {
+    match split_guess_row(row) {
+        Some((word, feedback)) => {
+            let separator = &row[word.len()..row.len() - feedback.len()];
+            let feedback: Vec<String> = feedback.split('/').map(fix_feedback).collect();
+            format!("{}{}{}", fix_layout(word), separator, feedback.join("/"))
+        }
+        None => fix_layout(row),
+    }
+}
+
+// Struct for the layout correction applied to typed input, which the user can switch off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutFix {
+    enabled: bool,
+}
+
+impl LayoutFix {
+    pub fn new(enabled: bool) -> Self {
+        LayoutFix { enabled }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// A word or pattern, see `fix_layout`
+    pub fn word(&self, text: &str) -> String {
+        if self.enabled { fix_layout(text) } else { text.to_string() }
+    }
+
+    /// A reject list, see `fix_rejects`
+    pub fn rejects(&self, text: &str) -> String {
+        if self.enabled { fix_rejects(text) } else { text.to_string() }
+    }
+
+    /// A guess row with its feedback, see `fix_guess_row`
+    pub fn guess_row(&self, text: &str) -> String {
+        if self.enabled { fix_guess_row(text) } else { text.to_string() }
+    }
+}
+
+impl Default for LayoutFix {
+    fn default() -> Self {
+        LayoutFix::new(true)
+    }
+}
@@ -1,10 +1,30 @@
+// The original pattern tests compare booleans with `assert_eq!`
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+
 // External Dependencies
 use rusqlite::{Connection, Result};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::OnceLock;
 use thiserror::Error;
 use regex::Regex;
 use unicode_segmentation::UnicodeSegmentation;
 
+pub mod absurdle;
+pub mod analysis;
+pub mod bench;
+pub mod daily;
+pub mod dict;
+pub mod game;
+pub mod hint;
+pub mod index;
+pub mod layout;
+pub mod multi;
+pub mod scoring;
+pub mod session;
+pub mod share;
+pub mod solver;
+pub mod source;
+
 // Error Definitions
 #[derive(Error, Debug)]
 pub enum WordleQueryError {
@@ -14,6 +34,12 @@ pub enum WordleQueryError {
     QueryError(String),
     #[error("Regex pattern not valid")]
     InvalidRegexPattern(String),
+    #[error("Import error: {0}")]
+    ImportError(String),
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid guess: {0}")]
+    InvalidGuess(String),
 }
 
 // Struct for Wordle Word and Methods
@@ -28,24 +54,24 @@ impl Wordle {
         lemma.replace('ё', "е")
     }
 
-    /// Calculate the score of a word based on letter frequencies
+    /// Calculate the score of a word based on letter frequencies.
+    /// Letters missing from the table count as `UNKNOWN_LETTER_FREQ`.
     pub fn calculate_score(lemma: &str, ru_letter_freqs: &HashMap<char, f64>) -> f64 {
-        let mut score = 1.0;
-        for c in lemma.chars() {
-            if let Some(&letter_score) = ru_letter_freqs.get(&c) {
-                score *= letter_score;
-            } else {
-                break;
-            }
-        }
-        score
+        lemma.chars()
+            .map(|c| ru_letter_freqs.get(&c).copied().unwrap_or(scoring::UNKNOWN_LETTER_FREQ))
+            .product()
     }
 
-    /// Create a new Wordle instance
+    /// Create a new Wordle instance scored by general letter frequencies
     pub fn new(lemma: String) -> Self {
+        static SCORER: OnceLock<scoring::FrequencyProduct> = OnceLock::new();
+        Self::with_scorer(lemma, SCORER.get_or_init(scoring::FrequencyProduct::default))
+    }
+
+    /// Create a new Wordle instance scored by any `Scorer`
+    pub fn with_scorer(lemma: String, scorer: &dyn scoring::Scorer) -> Self {
         let lemma = Self::replace_yo(&lemma);
-        let ru_letter_freqs = Self::init_letter_freqs();
-        let score = Self::calculate_score(&lemma, &ru_letter_freqs);
+        let score = scorer.score(&lemma);
         Wordle { lemma, score }
     }
 
@@ -62,26 +88,343 @@ impl Wordle {
     }
 }
 
+// Enum for the ways suggested guesses can be ranked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Highest word score first, see `scoring::Scorer`
+    Frequency,
+    /// Most expected information over the remaining candidates
+    Entropy,
+    /// Fewest candidates left in the worst case
+    Minimax,
+    /// Fewest candidates left on average
+    ExpectedRemaining,
+}
+
+impl std::str::FromStr for Strategy {
+    type Err = WordleQueryError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "frequency" => Ok(Strategy::Frequency),
+            "entropy" => Ok(Strategy::Entropy),
+            "minimax" => Ok(Strategy::Minimax),
+            "expected" => Ok(Strategy::ExpectedRemaining),
+            _ => Err(WordleQueryError::QueryError(format!(
+                "Unknown strategy '{}', expected frequency, entropy, minimax or expected.",
+                name
+            ))),
+        }
+    }
+}
+
+/// Default number of letters in a Wordle answer
+pub const WORD_LENGTH: usize = 5;
+
+/// Longest word the solver can handle, since its base-3 feedback codes must fit in a `u32`
+pub const MAX_WORD_LENGTH: usize = 20;
+
+// Enum for the tile colours shown by the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Green,
+    Yellow,
+    Grey,
+}
+
+impl Colour {
+    /// Parse a single feedback character, either Latin (G/Y/B) or Russian (з/ж/с)
+    pub fn from_char(c: char) -> Option<Self> {
+        match c.to_lowercase().next()? {
+            'g' | 'з' => Some(Colour::Green),
+            'y' | 'ж' => Some(Colour::Yellow),
+            'b' | 'с' => Some(Colour::Grey),
+            _ => None,
+        }
+    }
+}
+
+// Struct for the constraints accumulated from patterns and guesses
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+    /// Letter known to be at each position (green)
+    pub greens: Vec<Option<char>>,
+    /// Letters known not to be at each position (yellow, or grey duplicates)
+    pub misplaced: Vec<Vec<char>>,
+    /// Letters that must appear somewhere in the word
+    pub required: Vec<char>,
+    /// Letters that must not appear anywhere in the word
+    pub rejects: Vec<char>,
+    /// Lower bound on the copies of a letter, learned from repeated green/yellow tiles
+    pub min_counts: BTreeMap<char, usize>,
+    /// Upper bound on the copies of a letter, learned from grey duplicates
+    pub max_counts: BTreeMap<char, usize>,
+}
+
+impl Constraints {
+    /// Create an empty constraint set for words of the given length
+    pub fn new(length: usize) -> Self {
+        Constraints {
+            greens: vec![None; length],
+            misplaced: vec![Vec::new(); length],
+            required: Vec::new(),
+            rejects: Vec::new(),
+            min_counts: BTreeMap::new(),
+            max_counts: BTreeMap::new(),
+        }
+    }
+
+    /// Number of letters in the words these constraints describe
+    pub fn length(&self) -> usize {
+        self.greens.len()
+    }
+
+    /// Build constraints from a pattern in the uppercase/lowercase/`_x`/`*` syntax,
+    /// optionally followed by letter counts such as `/о=2` (exactly) or `/о+2` (at least)
+    pub fn from_pattern(pattern: &str, rejects: &[char]) -> Result<Self, WordleQueryError> {
+        let (cells, counts) = split_pattern_counts(pattern);
+        let row = pattern_row(cells);
+        let mut constraints = Constraints::new(row.len());
+        constraints.apply_row(&row);
+        for &reject in rejects {
+            push_unique(&mut constraints.rejects, reject);
+        }
+        for count in counts {
+            constraints.add_count(count)?;
+        }
+        Ok(constraints)
+    }
+
+    /// Add a single `о=2` or `о+2` letter count
+    fn add_count(&mut self, spec: &str) -> Result<(), WordleQueryError> {
+        let invalid = || WordleQueryError::QueryError(format!(
+            "Letter count '{}' must look like о=2 (exactly) or о+2 (at least).", spec
+        ));
+        let mut chars = spec.chars();
+        let c = chars.next()
+            .filter(|c| c.is_alphabetic())
+            .map(|c| convert_latin_to_cyrillic(convert_ye_to_yo(c.to_lowercase().next().unwrap())))
+            .ok_or_else(invalid)?;
+        let op = chars.next().ok_or_else(invalid)?;
+        let count: usize = chars.as_str().parse().map_err(|_| invalid())?;
+        if count > 0 {
+            push_unique(&mut self.required, c);
+        }
+        match op {
+            '=' => {
+                self.raise_count(c, count);
+                self.cap_count(c, count);
+            }
+            '+' => self.raise_count(c, count),
+            _ => return Err(invalid()),
+        }
+        Ok(())
+    }
+
+    /// Constraints implied by a single guess and the colours the game showed for it
+    pub fn from_feedback(guess: &str, feedback: &[Colour]) -> Result<Self, WordleQueryError> {
+        let mut constraints = Constraints::new(guess.chars().count());
+        constraints.add_guess(guess, feedback)?;
+        Ok(constraints)
+    }
+
+    /// Add the constraints implied by one guess and the colours the game showed for it
+    pub fn add_guess(&mut self, guess: &str, feedback: &[Colour]) -> Result<(), WordleQueryError> {
+        let letters: Vec<char> = guess.chars().collect();
+        if letters.len() != self.length() || feedback.len() != self.length() {
+            return Err(WordleQueryError::QueryError(format!(
+                "Guess and feedback must both contain exactly {} letters.",
+                self.length()
+            )));
+        }
+
+        let row: Vec<Option<(char, Colour)>> = letters.into_iter().zip(feedback.iter().copied()).map(Some).collect();
+        self.apply_row(&row);
+        Ok(())
+    }
+
+    /// Apply one scored row, where `None` marks a position with no information
+    fn apply_row(&mut self, row: &[Option<(char, Colour)>]) {
+        // Copies of each letter that scored green or yellow in this row
+        let mut found: HashMap<char, usize> = HashMap::new();
+        for &(c, colour) in row.iter().flatten() {
+            if colour != Colour::Grey {
+                *found.entry(c).or_insert(0) += 1;
+            }
+        }
+        for (&c, &count) in &found {
+            if count > 1 {
+                self.raise_count(c, count);
+            }
+        }
+
+        for (i, cell) in row.iter().enumerate() {
+            let Some((c, colour)) = *cell else { continue };
+            match colour {
+                Colour::Green => self.greens[i] = Some(c),
+                Colour::Yellow => {
+                    push_unique(&mut self.required, c);
+                    push_unique(&mut self.misplaced[i], c);
+                }
+                // A grey duplicate means "not here, and no more copies than were found"
+                Colour::Grey => match found.get(&c) {
+                    Some(&count) => {
+                        push_unique(&mut self.misplaced[i], c);
+                        self.cap_count(c, count);
+                    }
+                    None => push_unique(&mut self.rejects, c),
+                },
+            }
+        }
+    }
+
+    /// Raise the minimum number of copies required for a letter
+    fn raise_count(&mut self, c: char, count: usize) {
+        let min = self.min_counts.entry(c).or_insert(count);
+        *min = (*min).max(count);
+    }
+
+    /// Lower the maximum number of copies allowed for a letter
+    fn cap_count(&mut self, c: char, count: usize) {
+        let max = self.max_counts.entry(c).or_insert(count);
+        *max = (*max).min(count);
+    }
+
+    /// Number of copies of a letter the constraints already guarantee
+    pub fn known_count(&self, c: char) -> usize {
+        let greens = self.greens.iter().filter(|&&g| g == Some(c)).count();
+        let required = usize::from(self.required.contains(&c));
+        let min = self.min_counts.get(&c).copied().unwrap_or(0);
+        greens.max(required).max(min)
+    }
+
+    /// Split the rejects into letters absent from the word and count caps.
+    /// A rejected letter that is also known to be present only caps its count.
+    fn absent_and_caps(&self) -> (Vec<char>, BTreeMap<char, usize>) {
+        let mut absent = Vec::new();
+        let mut max_counts = self.max_counts.clone();
+        for &reject in &self.rejects {
+            match self.known_count(reject) {
+                0 => absent.push(reject),
+                known => {
+                    let max = max_counts.entry(reject).or_insert(known);
+                    *max = (*max).min(known);
+                }
+            }
+        }
+        (absent, max_counts)
+    }
+
+    /// Check a lowercase word against the constraints without going through SQL.
+    /// Applies the same rules as `WordleQuery::build_query`.
+    pub fn matches(&self, word: &str) -> bool {
+        let letters: Vec<char> = word.chars().collect();
+        if letters.len() != self.length()
+            || !letters.first().is_some_and(|c| ('а'..='я').contains(c))
+            || letters.iter().any(|&c| c == '-' || c == '.')
+        {
+            return false;
+        }
+
+        let count = |c: char| letters.iter().filter(|&&l| l == c).count();
+        let greens_ok = self.greens.iter().zip(&letters).all(|(green, &l)| green.is_none_or(|g| g == l));
+        let misplaced_ok = self.misplaced.iter().zip(&letters).all(|(bad, l)| !bad.contains(l));
+        let (absent, max_counts) = self.absent_and_caps();
+
+        greens_ok
+            && misplaced_ok
+            && self.required.iter().all(|&c| count(c) > 0)
+            && absent.iter().all(|&c| count(c) == 0)
+            && self.min_counts.iter().all(|(&c, &min)| count(c) >= min)
+            && max_counts.iter().all(|(&c, &max)| count(c) <= max)
+    }
+
+    /// Combine another constraint set of the same length into this one
+    pub fn merge(&mut self, other: &Constraints) {
+        for (i, green) in other.greens.iter().enumerate() {
+            if green.is_some() {
+                self.greens[i] = *green;
+            }
+        }
+        for (i, letters) in other.misplaced.iter().enumerate() {
+            for &c in letters {
+                push_unique(&mut self.misplaced[i], c);
+            }
+        }
+        for &c in &other.required {
+            push_unique(&mut self.required, c);
+        }
+        for &c in &other.rejects {
+            push_unique(&mut self.rejects, c);
+        }
+        for (&c, &count) in &other.min_counts {
+            self.raise_count(c, count);
+        }
+        for (&c, &count) in &other.max_counts {
+            self.cap_count(c, count);
+        }
+    }
+}
+
+impl std::fmt::Display for Constraints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |letters: &[char]| letters.iter().map(char::to_string).collect::<Vec<_>>().join(", ");
+
+        let greens: String = self.greens.iter().map(|g| g.map_or('*', |c| c.to_uppercase().next().unwrap())).collect();
+        writeln!(f, "known:     {}", greens)?;
+        let misplaced: Vec<String> = self.misplaced.iter().enumerate()
+            .filter(|(_, letters)| !letters.is_empty())
+            .map(|(i, letters)| format!("{}: {}", i + 1, join(letters)))
+            .collect();
+        writeln!(f, "not at:    {}", misplaced.join("; "))?;
+        writeln!(f, "required:  {}", join(&self.required))?;
+        writeln!(f, "rejected:  {}", join(&self.rejects))?;
+        let mut counts: Vec<String> = Vec::new();
+        for (c, min) in &self.min_counts {
+            counts.push(format!("{} >= {}", c, min));
+        }
+        for (c, max) in &self.max_counts {
+            counts.push(format!("{} <= {}", c, max));
+        }
+        write!(f, "counts:    {}", counts.join(", "))
+    }
+}
+
 // Struct for WordleQuery and Methods
 #[derive(Debug)]
 pub struct WordleQuery {
-    pub pattern: String,
-    pub rejects: Vec<char>,
+    pub constraints: Constraints,
 }
 
 impl WordleQuery {
     /// Create a new WordleQuery instance, validate input pattern and rejects
     pub fn new(pattern: &str, rejects: &str) -> Result<Self, WordleQueryError> {
+        Self::with_length(pattern, rejects, WORD_LENGTH)
+    }
+
+    /// Create a new WordleQuery instance for words of the given length
+    pub fn with_length(pattern: &str, rejects: &str, length: usize) -> Result<Self, WordleQueryError> {
         println!("Pattern = {}", pattern);
 
-        if !is_valid_pattern(pattern) {
-            return Err(WordleQueryError::QueryError(
-                "Pattern must contain exactly 5 Cyrillic (or *) characters.".to_string(),
-            ));
+        if !is_valid_pattern_of_length(pattern, length) {
+            return Err(WordleQueryError::QueryError(format!(
+                "Pattern must contain exactly {} Cyrillic (or *) characters.",
+                length
+            )));
         }
 
-        let rejects = process_rejects(rejects);
-        Ok(WordleQuery { pattern: pattern.to_string(), rejects })
+        let constraints = Constraints::from_pattern(pattern, &process_rejects(rejects))?;
+        Ok(WordleQuery { constraints })
+    }
+
+    /// Number of letters in the words this query matches
+    pub fn length(&self) -> usize {
+        self.constraints.length()
+    }
+
+    /// Create a WordleQuery directly from an accumulated constraint set
+    pub fn from_constraints(constraints: Constraints) -> Self {
+        WordleQuery { constraints }
     }
 
     /// Extracts rejects from the pattern and modifies the pattern
@@ -97,31 +440,63 @@ impl WordleQuery {
         Ok(collected_letters)
     }
 
-    /// Build SQL query for the Wordle database
-    pub fn build_query(&self) -> String {
-        let mut query = String::from("SELECT w.word FROM words w WHERE LENGTH(w.word) = 5");
+    /// Build SQL query for the Wordle database, returning the SQL and its bound parameters.
+    /// Letters are never spliced into the SQL text, so any input is safe to pass through.
+    pub fn build_query(&self) -> (String, Vec<String>) {
+        let constraints = &self.constraints;
+        let mut params: Vec<String> = Vec::new();
+        let mut bind = |c: char| {
+            params.push(c.to_string());
+            format!("?{}", params.len())
+        };
+
+        let mut query = format!("SELECT w.word FROM words w WHERE LENGTH(w.word) = {}", constraints.length());
         query.push_str(" AND w.word GLOB '[а-я]*'");
         query.push_str(" AND w.word NOT LIKE '%-%'");
         query.push_str(" AND w.word NOT LIKE '%.%'");
 
-        for (i, c) in self.pattern.chars().enumerate() {
-            match c {
-                '*' => {}
-                _ if c.is_uppercase() => {
-                    query.push_str(&format!(" AND SUBSTR(w.word, {}, 1) = '{}'", i + 1, c.to_lowercase()));
-                }
-                _ if c.is_lowercase() => {
-                    query.push_str(&format!(" AND w.word LIKE '%{}%' AND SUBSTR(w.word, {}, 1) != '{}'", c, i + 1, c));
-                }
-                _ => {}
+        for (i, green) in constraints.greens.iter().enumerate() {
+            if let Some(c) = green {
+                query.push_str(&format!(" AND SUBSTR(w.word, {}, 1) = {}", i + 1, bind(*c)));
+            }
+        }
+
+        for c in &constraints.required {
+            query.push_str(&format!(" AND INSTR(w.word, {}) > 0", bind(*c)));
+        }
+
+        for (i, letters) in constraints.misplaced.iter().enumerate() {
+            for c in letters {
+                query.push_str(&format!(" AND SUBSTR(w.word, {}, 1) != {}", i + 1, bind(*c)));
             }
         }
 
-        for reject in &self.rejects {
-            query.push_str(&format!(" AND w.word NOT LIKE '%{}%'", reject));
+        let (absent, max_counts) = constraints.absent_and_caps();
+        for reject in absent {
+            query.push_str(&format!(" AND INSTR(w.word, {}) = 0", bind(reject)));
         }
 
-        query
+        for (c, min) in &constraints.min_counts {
+            if *min > 1 {
+                query.push_str(&format!(
+                    " AND LENGTH(w.word) - LENGTH(REPLACE(w.word, {}, '')) >= {}",
+                    bind(*c), min
+                ));
+            }
+        }
+
+        for (c, max) in &max_counts {
+            if *max == 0 {
+                query.push_str(&format!(" AND INSTR(w.word, {}) = 0", bind(*c)));
+            } else {
+                query.push_str(&format!(
+                    " AND LENGTH(w.word) - LENGTH(REPLACE(w.word, {}, '')) <= {}",
+                    bind(*c), max
+                ));
+            }
+        }
+
+        (query, params)
     }
 }
 
@@ -140,21 +515,114 @@ pub fn append_chars_to_comma_delimited_str(rejects: &str, chars_to_add: Vec<char
     result
 }
 
+fn push_unique(letters: &mut Vec<char>, c: char) {
+    if !letters.contains(&c) {
+        letters.push(c);
+    }
+}
+
+/// Colours the game shows for `guess` when the answer is `answer`, including the duplicate-letter rules:
+/// exact matches are green first, then each remaining copy of a letter in the answer turns at most one
+/// more copy in the guess yellow, left to right. Both words are lowercased and `ё` is read as `е`.
+pub fn feedback(guess: &str, answer: &str) -> Result<Vec<Colour>, WordleQueryError> {
+    let guess: Vec<char> = Wordle::replace_yo(&guess.to_lowercase()).chars().collect();
+    let answer: Vec<char> = Wordle::replace_yo(&answer.to_lowercase()).chars().collect();
+    if guess.len() != answer.len() {
+        return Err(WordleQueryError::QueryError(format!(
+            "Guess has {} letters but the answer has {}.",
+            guess.len(),
+            answer.len()
+        )));
+    }
+    Ok(solver::feedback_colours(&guess, &answer))
+}
+
+pub fn parse_feedback(feedback: &str) -> Result<Vec<Colour>, WordleQueryError> {
+    feedback.chars()
+        .map(|c| Colour::from_char(c).ok_or_else(|| {
+            WordleQueryError::QueryError(format!("Invalid feedback character '{}', expected G/Y/B or з/ж/с.", c))
+        }))
+        .collect()
+}
+
+/// Lowercase a typed word, read `ё` as `е` and fix Latin look-alikes of Cyrillic letters
+pub fn normalize_guess_word(word: &str) -> String {
+    word.trim().chars()
+        .flat_map(|c| c.to_lowercase())
+        .map(convert_ye_to_yo)
+        .map(convert_latin_to_cyrillic)
+        .collect()
+}
+
+/// `count` followed by "guess" or "guesses", as in "Solved in 1 guess"
+pub fn guess_count(count: usize) -> String {
+    format!("{} {}", count, if count == 1 { "guess" } else { "guesses" })
+}
+
+/// Split a guess row at the first `:`, `=` or whitespace, the separators every guess input accepts
+pub(crate) fn split_guess_row(input: &str) -> Option<(&str, &str)> {
+    input.split_once(|c: char| c == ':' || c == '=' || c.is_whitespace())
+}
+
+/// Split a guess row such as `слово:GYBBG` into the guessed word and its feedback
+pub fn parse_guess(input: &str) -> Result<(String, Vec<Colour>), WordleQueryError> {
+    let (word, feedback) = split_guess_row(input)
+        .ok_or_else(|| WordleQueryError::QueryError(
+            format!("Guess '{}' must look like WORD:FEEDBACK, e.g. слово:GYBBG.", input),
+        ))?;
+    let feedback = parse_feedback(feedback.trim())?;
+    Ok((normalize_guess_word(word), feedback))
+}
+
+/// Split `**о*о/о=2/а+1` into the positional cells and the letter count suffixes
+fn split_pattern_counts(pattern: &str) -> (&str, Vec<&str>) {
+    let mut parts = pattern.split('/');
+    let cells = parts.next().unwrap_or("");
+    (cells, parts.filter(|part| !part.is_empty()).collect())
+}
+
+/// Split a pattern into one cell per position, marking `_x` cells as grey
+fn pattern_row(pattern: &str) -> Vec<Option<(char, Colour)>> {
+    let mut row = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        row.push(match c {
+            '_' => chars.next().map(|c| (c.to_lowercase().next().unwrap(), Colour::Grey)),
+            _ if c.is_uppercase() => Some((c.to_lowercase().next().unwrap(), Colour::Green)),
+            _ if c.is_lowercase() => Some((c, Colour::Yellow)),
+            _ => None,
+        });
+    }
+    row
+}
+
+/// Replace `_x` cells with `*`, collecting letters that are absent from the word.
+/// A grey letter that also appears unprefixed elsewhere in the pattern is a
+/// duplicate rather than a reject, so it is not collected.
 pub fn parse_pattern(input: &str) -> (String, Vec<char>) {
+    let found: Vec<char> = pattern_row(split_pattern_counts(input).0).into_iter().flatten()
+        .filter(|&(_, colour)| colour != Colour::Grey)
+        .map(|(c, _)| c)
+        .collect();
     let re = Regex::new(r"_([\p{Cyrillic}])").unwrap();
     let mut collected_rejects = Vec::new();
     let modified_pattern = re.replace_all(input, |captures: &regex::Captures| {
         if let Some(c) = captures.get(1) {
-            collected_rejects.push(c.as_str().chars().next().unwrap());
+            let c = c.as_str().chars().next().unwrap();
+            if !found.contains(&c.to_lowercase().next().unwrap()) {
+                collected_rejects.push(c);
+            }
         }
         "*"
     }).to_string();
     (modified_pattern, collected_rejects)
 }
 
-pub fn load_words_from_query(query: &str, conn: &Connection) -> Result<HashSet<String>> {
-    let mut stmt = conn.prepare(query)?;
-    let word_iter = stmt.query_map([], |row| {
+/// Run a query from `WordleQuery::build_query`, reusing the cached prepared statement
+/// when the same SQL is seen again with different parameters
+pub fn load_words_from_query(query: &str, params: &[String], conn: &Connection) -> Result<HashSet<String>> {
+    let mut stmt = conn.prepare_cached(query)?;
+    let word_iter = stmt.query_map(rusqlite::params_from_iter(params), |row| {
         let word: String = row.get(0)?;
         Ok(word)
     })?;
@@ -187,12 +655,17 @@ pub fn process_rejects(rejects: &str) -> Vec<char> {
 }
 
 pub fn is_valid_pattern(pattern: &str) -> bool {
+    is_valid_pattern_of_length(pattern, WORD_LENGTH)
+}
+
+pub fn is_valid_pattern_of_length(pattern: &str, length: usize) -> bool {
+    let (pattern, _) = split_pattern_counts(pattern);
     let re = Regex::new(r"_([a-яё])").unwrap();
     let modified_pattern = re.replace_all(pattern, |_: &regex::Captures| {
         "*"
     });
     let pattern_length = UnicodeSegmentation::graphemes(&*modified_pattern, true).count();
-    pattern_length == 5
+    pattern_length == length
 }
 
 // Unit Tests
@@ -278,3 +751,17 @@ fn test_valid_pattern_without_rejcts_has_letters() {
     let pattern = "**И*а";
     assert_eq!(is_valid_pattern(pattern), true);
 }
+
+#[test]
+fn test_parse_pattern_keeps_grey_duplicate_of_found_letter() {
+    let (modified_pattern, rejects) = parse_pattern("С*_с_т*");
+    assert_eq!(modified_pattern, "С****");
+    assert_eq!(rejects, vec!['т']);
+}
+
+#[test]
+fn test_valid_pattern_of_other_lengths() {
+    assert!(is_valid_pattern_of_length("**_о*Т*", 6));
+    assert!(is_valid_pattern_of_length("****", 4));
+    assert!(!is_valid_pattern_of_length("*****", 6));
+}
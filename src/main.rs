@@ -2,11 +2,29 @@ use clap::{Arg, Command};
 use rusqlite::Connection;
 use std::time::Instant;
 use prettytable::{Table, row};
+use std::collections::HashSet;
 use std::fs;
+use std::io::{self, BufRead, Write};
 use std::path::PathBuf;
-use dirs;
 use serde_json::Value;
-use rust_russian_wordle::{is_valid_pattern, parse_pattern, WordleQuery, load_words_from_query, Wordle};
+use rust_russian_wordle::absurdle::Absurdle;
+use rust_russian_wordle::analysis::analyse_game;
+use rust_russian_wordle::bench::{self, BenchReport, Simulator};
+use rust_russian_wordle::daily::{self, DailyPuzzle, PuzzleDate};
+use rust_russian_wordle::dict::{self, DictFormat};
+use rust_russian_wordle::scoring::{FrequencyProduct, LogProbability, PositionalFrequencies, Scorer, UniqueLetters};
+use rust_russian_wordle::game::{self, Game, GameStatus};
+use rust_russian_wordle::hint::{give_hint, HintKind};
+use rust_russian_wordle::index::WordIndex;
+use rust_russian_wordle::layout::LayoutFix;
+use rust_russian_wordle::multi::{parse_multi_guess, rank_multi_guesses, MultiBoard};
+use rust_russian_wordle::session::Session;
+use rust_russian_wordle::share::{self, ShareCard};
+use rust_russian_wordle::solver::rank_guesses;
+#[cfg(feature = "embedded-words")]
+use rust_russian_wordle::source::MemorySource;
+use rust_russian_wordle::source::{FileSource, SqliteSource, WordSource};
+use rust_russian_wordle::{guess_count, is_valid_pattern_of_length, normalize_guess_word, parse_guess, Constraints, WordleQuery, Colour, Strategy, Wordle, WordleQueryError, MAX_WORD_LENGTH, WORD_LENGTH};
 
 fn load_config() -> Option<String> {
     let config_path = dirs::home_dir()
@@ -56,6 +74,665 @@ fn remove_config() {
     }
 }
 
+// Enum for the word scores shown in the suggestion tables
+#[derive(Clone, Copy, PartialEq)]
+enum ScoreKind {
+    /// General Russian letter frequencies, see `Wordle::calculate_score`
+    Frequency,
+    /// General letter frequencies, counting each distinct letter once
+    Unique,
+    /// Log-probability of the word under general letter frequencies
+    LogProbability,
+    /// Per-position letter frequencies over the remaining candidates
+    PositionalCandidates,
+    /// Per-position letter frequencies over the whole dictionary
+    PositionalDictionary,
+}
+
+// Struct for the options that control how suggestions are ranked and shown
+struct SuggestOptions {
+    strategy: Strategy,
+    score: ScoreKind,
+    length: usize,
+    limit: usize,
+    /// Puzzle number shown in the share text once the puzzle is solved
+    puzzle: Option<u64>,
+    /// Share only a spoiler-free summary instead of the emoji grid
+    summary: bool,
+    /// Hints to print instead of the candidate table
+    hints: Vec<HintKind>,
+    /// Guesses the puzzle allowed, shown in the share text
+    max_attempts: usize,
+    /// Whether the puzzle was played in hard mode, marked with `*` in the share text
+    hard_mode: bool,
+    /// Correction for guesses typed on the QWERTY layout
+    layout: LayoutFix,
+}
+
+/// Look up the candidates matching the constraints and print them ranked by the strategy
+fn print_suggestions(options: &SuggestOptions, constraints: Constraints, source: &dyn WordSource) -> Result<(), WordleQueryError> {
+    let words = source.candidates(&constraints)?;
+    let scorer = build_scorer(options, &words, source)?;
+
+    match options.strategy {
+        Strategy::Frequency => {
+            println!("Remaining candidates: {}", words.len());
+            print_score_ranking(words, options.limit, scorer.as_ref());
+        }
+        _ => print_guess_ranking(options, words, scorer.as_ref(), source)?,
+    }
+    Ok(())
+}
+
+/// Print the requested hints, or the ranked candidates when no hint was asked for
+fn print_results(options: &SuggestOptions, constraints: Constraints, index: &WordIndex, source: &dyn WordSource) -> Result<(), WordleQueryError> {
+    if options.hints.is_empty() {
+        return print_suggestions(options, constraints, index);
+    }
+    for &kind in &options.hints {
+        print_hint(kind, options, &constraints, index, source)?;
+    }
+    Ok(())
+}
+
+/// Print a single hint about the candidates without listing them
+fn print_hint(kind: HintKind, options: &SuggestOptions, constraints: &Constraints, index: &WordIndex, source: &dyn WordSource) -> Result<(), WordleQueryError> {
+    let candidates: Vec<String> = index.filter(constraints).into_iter().map(str::to_string).collect();
+    let scorer = build_scorer(options, &candidates.iter().cloned().collect(), index)?;
+    let hint = give_hint(kind, constraints, &candidates, index.words(), options.strategy, scorer.as_ref(), source)?;
+    println!("Hint {}: {}", kind as usize + 1, hint);
+    Ok(())
+}
+
+/// Build the scorer used for the score column
+fn build_scorer(options: &SuggestOptions, words: &HashSet<String>, source: &dyn WordSource) -> Result<Box<dyn Scorer>, WordleQueryError> {
+    let source = match options.score {
+        ScoreKind::Frequency => return Ok(Box::new(FrequencyProduct::default())),
+        ScoreKind::Unique => return Ok(Box::new(UniqueLetters::default())),
+        ScoreKind::LogProbability => return Ok(Box::new(LogProbability::default())),
+        ScoreKind::PositionalCandidates => normalize_words(words.clone()),
+        ScoreKind::PositionalDictionary => load_dictionary(options.length, source)?,
+    };
+    let freqs = PositionalFrequencies::from_words(source.iter().map(String::as_str), options.length);
+    Ok(Box::new(freqs))
+}
+
+/// Every word of the given length in the word source
+fn load_dictionary(length: usize, source: &dyn WordSource) -> Result<Vec<String>, WordleQueryError> {
+    Ok(normalize_words(source.words_of_length(length)?))
+}
+
+/// Print a prompt and read the next trimmed line, or `None` at end of input
+fn prompt(lines: &mut impl Iterator<Item = io::Result<String>>, text: &str) -> Option<String> {
+    print!("{}", text);
+    io::stdout().flush().ok()?;
+    lines.next()?.ok().map(|line| line.trim().to_string())
+}
+
+fn print_interactive_help() {
+    println!("Enter a guess, then the colours the game showed (G/Y/B or з/ж/с).");
+    println!("A whole row can also be entered at once, e.g. слово:GYBBG.");
+    println!("Commands:");
+    println!("  undo   remove the last guess");
+    println!("  reset  remove every guess");
+    println!("  show   show the guesses and the constraints derived from them");
+    println!("  hint   reveal the next hint: count, letter, repeat, pos, then guess");
+    println!("         or ask for one directly, e.g. hint letter");
+    println!("  help   show this message");
+    println!("  quit   leave the session");
+}
+
+/// Prompt for guesses and feedback until the user quits, keeping the word source open
+fn run_interactive(mut session: Session, options: &SuggestOptions, index: &WordIndex, source: &dyn WordSource) -> Result<(), WordleQueryError> {
+    print_interactive_help();
+    print_results(options, session.constraints(), index, source)?;
+
+    // Hints are revealed one at a time and start over whenever the guesses change
+    let mut next_hint = Some(HintKind::Count);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    while let Some(input) = prompt(&mut lines, "guess> ") {
+        match input.as_str() {
+            "" => continue,
+            "quit" | "exit" => break,
+            "help" => {
+                print_interactive_help();
+                continue;
+            }
+            "hint" => {
+                match next_hint {
+                    Some(kind) => {
+                        print_hint(kind, options, &session.constraints(), index, source)?;
+                        next_hint = kind.next();
+                    }
+                    None => println!("No more hints"),
+                }
+                continue;
+            }
+            _ if input.starts_with("hint ") => {
+                match input["hint ".len()..].parse() {
+                    Ok(kind) => print_hint(kind, options, &session.constraints(), index, source)?,
+                    Err(e) => eprintln!("Error: {}", e),
+                }
+                continue;
+            }
+            "show" => {
+                for (guess, feedback) in session.rows() {
+                    println!("{} {}", guess, format_feedback(feedback));
+                }
+                println!("{}", session.constraints());
+                continue;
+            }
+            "undo" => match session.undo() {
+                Some((guess, _)) => println!("Removed {}", guess),
+                None => {
+                    println!("Nothing to undo");
+                    continue;
+                }
+            },
+            "reset" => {
+                session.reset();
+                println!("Cleared all guesses");
+            }
+            _ => {
+                let row = if input.contains([':', '=', ' ']) {
+                    input.clone()
+                } else {
+                    let Some(feedback) = prompt(&mut lines, "feedback> ") else { break };
+                    format!("{}:{}", input, feedback)
+                };
+                let result = parse_guess(&options.layout.guess_row(&row)).and_then(|(word, feedback)| session.add_guess(&word, &feedback));
+                if let Err(e) = result {
+                    eprintln!("Error: {}", e);
+                    continue;
+                }
+                if print_solved(session.rows(), options) {
+                    continue;
+                }
+            }
+        }
+        next_hint = Some(HintKind::Count);
+        print_results(options, session.constraints(), index, source)?;
+    }
+    Ok(())
+}
+
+/// Once the last guess row is all green, print the result with its share text and return `true`
+fn print_solved(rows: &[(String, Vec<Colour>)], options: &SuggestOptions) -> bool {
+    let Some((_, feedback)) = rows.last() else { return false };
+    if !feedback.iter().all(|&colour| colour == Colour::Green) {
+        return false;
+    }
+    println!("Solved in {}!", guess_count(rows.len()));
+    if rows.len() > options.max_attempts {
+        println!("That is more than the {} allowed, so there is nothing to share.", guess_count(options.max_attempts));
+        return true;
+    }
+    let colours = rows.iter().map(|(_, colours)| colours.clone()).collect();
+    let card = ShareCard::new(colours, options.max_attempts)
+        .with_puzzle(options.puzzle)
+        .with_hard_mode(options.hard_mode);
+    print_share(&card, options.summary);
+    true
+}
+
+/// Render feedback back into the G/Y/B letters used on input
+fn format_feedback(feedback: &[Colour]) -> String {
+    feedback
+        .iter()
+        .map(|colour| match colour {
+            Colour::Green => 'G',
+            Colour::Yellow => 'Y',
+            Colour::Grey => 'B',
+        })
+        .collect()
+}
+
+/// Print the candidates ordered by score
+fn print_score_ranking(words: HashSet<String>, limit: usize, scorer: &dyn Scorer) {
+    let mut wordles: Vec<Wordle> = normalize_words(words)
+        .into_iter()
+        .map(|lemma| Wordle::with_scorer(lemma, scorer))
+        .collect();
+
+    wordles.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+
+    if limit > 0 && wordles.len() > limit {
+        wordles.truncate(limit);
+    }
+
+    let mut table = Table::new();
+    table.add_row(row!["lemma", "score"]);
+    for wordle in &wordles {
+        table.add_row(row![wordle.lemma, scorer.display(wordle.score)]);
+    }
+
+    table.printstd();
+}
+
+/// Print the best guesses from the whole dictionary ordered by how they split the candidates
+fn print_guess_ranking(options: &SuggestOptions, words: HashSet<String>, scorer: &dyn Scorer, source: &dyn WordSource) -> Result<(), WordleQueryError> {
+    let candidates: Vec<String> = normalize_words(words);
+
+    // Any word of the right length is a legal guess, even one already ruled out as the answer
+    let guesses: Vec<String> = if candidates.len() > 2 {
+        load_dictionary(options.length, source)?
+    } else {
+        candidates.clone()
+    };
+
+    let mut rankings = rank_guesses(options.strategy, scorer, &guesses, &candidates);
+    if options.limit > 0 && rankings.len() > options.limit {
+        rankings.truncate(options.limit);
+    }
+
+    println!("Remaining candidates: {}", candidates.len());
+    let mut table = Table::new();
+    table.add_row(row!["lemma", "score", "bits", "worst", "expected", "candidate"]);
+    for ranking in &rankings {
+        let candidate = if ranking.is_candidate { "yes" } else { "" };
+        table.add_row(row![
+            ranking.guess,
+            scorer.display(ranking.score),
+            format!("{:.3}", ranking.bits),
+            ranking.worst_case,
+            format!("{:.2}", ranking.expected_remaining),
+            candidate
+        ]);
+    }
+
+    table.printstd();
+    Ok(())
+}
+
+/// Replace `ё` and drop the duplicates that creates, in a stable order
+fn normalize_words(words: HashSet<String>) -> Vec<String> {
+    let mut words: Vec<String> = words
+        .iter()
+        .map(|w| Wordle::replace_yo(w))
+        .collect::<HashSet<String>>()
+        .into_iter()
+        .collect();
+    words.sort();
+    words
+}
+
+/// Open the word list file or configured database, falling back to the embedded word list when neither is set
+fn open_source(wordlist: Option<&str>, db_path: Option<&str>) -> Result<Box<dyn WordSource>, WordleQueryError> {
+    match (wordlist, db_path) {
+        (Some(wordlist), _) => Ok(Box::new(FileSource::open(wordlist)?)),
+        (None, Some(db_path)) => Ok(Box::new(SqliteSource::open(db_path)?)),
+        #[cfg(feature = "embedded-words")]
+        (None, None) => Ok(Box::new(MemorySource::embedded()?)),
+        #[cfg(not(feature = "embedded-words"))]
+        (None, None) => Err(WordleQueryError::QueryError(
+            "No database path set. Use --setdbpath to set the database path.".to_string(),
+        )),
+    }
+}
+
+/// Guess rows from `--share` and `--share-words`, if a share grid was given
+fn load_share_rows(matches: &clap::ArgMatches) -> Result<Vec<(String, Vec<Colour>)>, WordleQueryError> {
+    let Some(path) = matches.get_one::<String>("share") else { return Ok(Vec::new()) };
+    let text = if path == "-" { io::read_to_string(io::stdin())? } else { fs::read_to_string(path)? };
+    let words: Vec<String> = matches
+        .get_one::<String>("share_words")
+        .map(|words| words.split(|c: char| c == ',' || c.is_whitespace()).filter(|w| !w.is_empty()).map(|w| layout_fix(matches).word(w)).collect())
+        .unwrap_or_default();
+    share::share_guesses(&share::parse_share_grid(&text)?, &words)
+}
+
+/// Correction for input typed on the QWERTY layout, unless `--no-layout-fix` was given
+fn layout_fix(matches: &clap::ArgMatches) -> LayoutFix {
+    LayoutFix::new(!matches.get_flag("no_layout_fix"))
+}
+
+/// Strategy selected by `--strategy`, or `entropy` for the older `--entropy` flag
+fn strategy(matches: &clap::ArgMatches) -> Result<Strategy, WordleQueryError> {
+    if matches.get_flag("entropy") {
+        return Ok(Strategy::Entropy);
+    }
+    matches.get_one::<String>("strategy").map(String::as_str).unwrap_or("frequency").parse()
+}
+
+/// Scorer selected by `--scorer` and `--freq-source`
+fn score_kind(matches: &clap::ArgMatches) -> ScoreKind {
+    match matches.get_one::<String>("scorer").map(String::as_str) {
+        Some("unique") => ScoreKind::Unique,
+        Some("logprob") => ScoreKind::LogProbability,
+        Some("positional") if matches.get_one::<String>("freq_source").map(String::as_str) == Some("dictionary") => {
+            ScoreKind::PositionalDictionary
+        }
+        Some("positional") => ScoreKind::PositionalCandidates,
+        _ => ScoreKind::Frequency,
+    }
+}
+
+/// Handle `bench`, playing every answer against the dictionary and printing the summary
+fn run_bench(matches: &clap::ArgMatches, db_path: Option<&str>) -> Result<(), WordleQueryError> {
+    let start = Instant::now();
+    let length = *matches.get_one::<usize>("length").unwrap_or(&WORD_LENGTH);
+    let strategy = strategy(matches)?;
+    let max_guesses = *matches.get_one::<usize>("max_guesses").unwrap_or(&bench::MAX_GUESSES);
+    let wordlist = matches.get_one::<String>("wordlist").map(String::as_str);
+
+    let source = open_source(wordlist, db_path)?;
+    let mut dictionary = source.words_of_length(length)?;
+    let answers: Vec<String> = match matches.get_one::<String>("answers") {
+        Some(path) => FileSource::open(path)?.words_of_length(length)?.into_iter().collect(),
+        None => dictionary.iter().cloned().collect(),
+    };
+    // Answers missing from the dictionary must still be playable guesses
+    dictionary.extend(answers.iter().cloned());
+    let index = WordIndex::new(&dictionary, length);
+    let mut answers = normalize_words(answers.into_iter().collect());
+    answers.retain(|answer| answer.chars().count() == length);
+
+    // The candidate pool changes every turn, so positional frequencies always come from the dictionary here
+    let options = SuggestOptions { strategy, score: score_kind(matches), length, limit: 0, puzzle: None, summary: false, hints: Vec::new(), max_attempts: game::DEFAULT_ATTEMPTS, hard_mode: false, layout: layout_fix(matches) };
+    let scorer = build_scorer(&options, &index.words().iter().cloned().collect(), &index)?;
+
+    let report = Simulator::new(&index, strategy, scorer.as_ref()).run(answers.iter().map(String::as_str), max_guesses)?;
+    print_bench_report(&report, *matches.get_one::<usize>("hardest").unwrap_or(&10));
+    println!("Elapsed time: {:.3}s", start.elapsed().as_secs_f64());
+    Ok(())
+}
+
+/// Handle `multi`, listing each board's candidates and the best shared guesses
+fn run_multi(matches: &clap::ArgMatches, db_path: Option<&str>) -> Result<(), WordleQueryError> {
+    let length = *matches.get_one::<usize>("length").unwrap_or(&WORD_LENGTH);
+    let limit = *matches.get_one::<usize>("limit").unwrap_or(&10);
+    let count = *matches.get_one::<usize>("boards").unwrap_or(&4);
+    if count == 0 {
+        return Err(WordleQueryError::QueryError("--boards must be at least 1".to_string()));
+    }
+    let wordlist = matches.get_one::<String>("wordlist").map(String::as_str);
+    let index = WordIndex::from_source(open_source(wordlist, db_path)?.as_ref(), length)?;
+
+    let mut boards = MultiBoard::new(count, length);
+    for guess in matches.get_many::<String>("guess").unwrap_or_default() {
+        let (word, feedback) = parse_multi_guess(&layout_fix(matches).guess_row(guess), count)?;
+        boards.add_guess(&word, &feedback)?;
+    }
+    if boards.all_solved() {
+        println!("All boards solved!");
+        return Ok(());
+    }
+
+    let candidates = boards.candidates(&index)?;
+    for (i, words) in candidates.iter().enumerate() {
+        if boards.is_solved(i) {
+            println!("Board {}: solved", i + 1);
+            continue;
+        }
+        let shown: Vec<&str> = words.iter().take(limit.max(1)).map(String::as_str).collect();
+        let more = if words.len() > shown.len() { ", ..." } else { "" };
+        println!("Board {}: {} candidates: {}{}", i + 1, words.len(), shown.join(", "), more);
+    }
+
+    // Any word may be played while a board still has more than two candidates
+    let guesses: Vec<String> = if candidates.iter().any(|words| words.len() > 2) {
+        index.words().to_vec()
+    } else {
+        candidates.iter().flatten().cloned().collect::<HashSet<String>>().into_iter().collect()
+    };
+    let mut rankings = rank_multi_guesses(&guesses, &candidates);
+    if limit > 0 {
+        rankings.truncate(limit);
+    }
+
+    let mut table = Table::new();
+    table.add_row(row!["lemma", "bits", "per board", "candidate on"]);
+    for ranking in &rankings {
+        let per_board: Vec<String> = ranking.board_bits.iter().map(|bits| format!("{:.2}", bits)).collect();
+        table.add_row(row![ranking.guess, format!("{:.3}", ranking.bits), per_board.join(" "), ranking.candidate_boards]);
+    }
+    table.printstd();
+    Ok(())
+}
+
+/// Handle `analyse`, replaying a finished game and printing a row per turn
+fn run_analysis(matches: &clap::ArgMatches, db_path: Option<&str>) -> Result<(), WordleQueryError> {
+    let length = *matches.get_one::<usize>("length").unwrap_or(&WORD_LENGTH);
+    let wordlist = matches.get_one::<String>("wordlist").map(String::as_str);
+    let layout = layout_fix(matches);
+    let answer = normalize_guess_word(&layout.word(matches.get_one::<String>("answer").unwrap()));
+    let guesses: Vec<String> = matches.get_many::<String>("guesses").unwrap_or_default().map(|g| layout.word(g)).collect();
+
+    // The answer counts as a candidate even if the dictionary does not have it
+    let mut dictionary = open_source(wordlist, db_path)?.words_of_length(length)?;
+    dictionary.insert(answer.clone());
+    let index = WordIndex::new(&dictionary, length);
+    let analysis = analyse_game(&index, &guesses, &answer)?;
+
+    let mut table = Table::new();
+    table.add_row(row!["turn", "guess", "feedback", "before", "left", "expected", "bits", "best", "best left", "best expected", "best bits", "skill"]);
+    for (i, turn) in analysis.turns.iter().enumerate() {
+        let tiles: String = turn.colours.iter().map(|&colour| share::tile_emoji(colour)).collect();
+        table.add_row(row![
+            i + 1,
+            turn.guess,
+            tiles,
+            turn.candidates_before,
+            turn.remaining,
+            format!("{:.2}", turn.expected_remaining),
+            format!("{:.3}", turn.bits),
+            turn.best_guess,
+            turn.best_remaining,
+            format!("{:.2}", turn.best_expected_remaining),
+            format!("{:.3}", turn.best_bits),
+            format!("{:.0}", turn.skill)
+        ]);
+    }
+    table.printstd();
+
+    if analysis.solved() {
+        println!("Solved in {}", guess_count(analysis.turns.len()));
+    } else {
+        println!("Not solved; the answer was {}", analysis.answer);
+    }
+    println!("Skill: {:.0}/100", analysis.skill());
+    Ok(())
+}
+
+fn print_bench_report(report: &BenchReport, hardest: usize) {
+    println!("Played {} games", report.results.len());
+
+    let mut table = Table::new();
+    table.add_row(row!["guesses", "games"]);
+    for (guesses, games) in report.distribution() {
+        table.add_row(row![guesses, games]);
+    }
+    table.printstd();
+
+    println!("Failed (more than {}): {}", guess_count(report.max_guesses), report.failures());
+    println!("Average guesses: {:.3}", report.average_guesses());
+
+    if hardest > 0 {
+        let mut table = Table::new();
+        table.add_row(row!["answer", "guesses", "path"]);
+        for result in report.hardest(hardest) {
+            let count = if result.solved { result.guess_count().to_string() } else { "unsolved".to_string() };
+            table.add_row(row![result.answer, count, result.guesses.join(" ")]);
+        }
+        println!("Hardest words:");
+        table.printstd();
+    }
+}
+
+/// Options shared by `play` and `daily`
+fn game_args() -> [Arg; 2] {
+    [
+        Arg::new("attempts")
+            .long("attempts")
+            .value_name("N")
+            .help("Number of guesses allowed")
+            .required(false)
+            .default_value("6")
+            .value_parser(clap::value_parser!(usize)),
+        Arg::new("hard")
+            .long("hard")
+            .help("Hard mode: greens must stay in place and revealed letters must be reused")
+            .required(false)
+            .action(clap::ArgAction::SetTrue),
+    ]
+}
+
+/// Handle `play` and `daily`, running a game on the terminal until it is won, lost or abandoned
+fn run_play(matches: &clap::ArgMatches, db_path: Option<&str>, daily: bool) -> Result<(), WordleQueryError> {
+    let length = *matches.get_one::<usize>("length").unwrap_or(&WORD_LENGTH);
+    let attempts = *matches.get_one::<usize>("attempts").unwrap_or(&game::DEFAULT_ATTEMPTS);
+    let wordlist = matches.get_one::<String>("wordlist").map(String::as_str);
+
+    let source = open_source(wordlist, db_path)?;
+    let index = WordIndex::from_source(source.as_ref(), length)?;
+    if index.is_empty() {
+        return Err(WordleQueryError::QueryError(format!("The dictionary has no {}-letter words.", length)));
+    }
+
+    let (answer, puzzle) = if daily {
+        let date = match matches.get_one::<String>("date") {
+            Some(date) => date.parse()?,
+            None => PuzzleDate::today(),
+        };
+        let salt = matches.get_one::<String>("salt").map(String::as_str).unwrap_or("");
+        let window = *matches.get_one::<usize>("window").unwrap_or(&daily::DEFAULT_WINDOW);
+        let puzzle = DailyPuzzle::new(index.words().iter().cloned(), salt, window);
+        println!("Daily puzzle #{} for {}", date.puzzle_number()?, date);
+        (puzzle.answer(date)?.to_string(), Some(date.puzzle_number()?))
+    } else {
+        (game::random_word(index.words()).cloned().unwrap_or_default(), matches.get_one::<u64>("puzzle").copied())
+    };
+    let mut game = Game::new(&answer, index.words().iter().cloned(), attempts, matches.get_flag("hard"));
+
+    println!("Guess the {}-letter word in {} attempts{}. Type quit to give up.", length, attempts, if game.is_hard_mode() { " (hard mode)" } else { "" });
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    while game.status() == GameStatus::InProgress {
+        let Some(input) = prompt(&mut lines, &format!("{}/{}> ", game.rows().len() + 1, attempts)) else { break };
+        match input.as_str() {
+            "" => continue,
+            "quit" | "exit" => break,
+            _ => {}
+        }
+        if let Err(e) = game.guess(&layout_fix(matches).word(&input)) {
+            eprintln!("{}", e);
+            continue;
+        }
+        println!();
+        print_board(game.rows());
+    }
+
+    match game.status() {
+        GameStatus::Won => println!("Solved in {}!", guess_count(game.rows().len())),
+        _ => println!("The word was {}", game.answer().to_uppercase()),
+    }
+    if game.status() != GameStatus::InProgress {
+        let rows = game.rows().iter().map(|(_, colours)| colours.clone()).collect();
+        let card = ShareCard::new(rows, attempts).with_puzzle(puzzle).with_hard_mode(game.is_hard_mode());
+        print_share(&card, matches.get_flag("summary"));
+    }
+    Ok(())
+}
+
+/// Print the share text, or only the spoiler-free summary
+fn print_share(card: &ShareCard, summary: bool) {
+    println!();
+    if summary {
+        println!("{}", card.summary());
+    } else {
+        println!("{}", card.text());
+    }
+}
+
+/// Handle `absurdle`, answering every guess with the colouring that keeps the most words alive
+fn run_absurdle(matches: &clap::ArgMatches, db_path: Option<&str>) -> Result<(), WordleQueryError> {
+    let length = *matches.get_one::<usize>("length").unwrap_or(&WORD_LENGTH);
+    let wordlist = matches.get_one::<String>("wordlist").map(String::as_str);
+    let index = WordIndex::from_source(open_source(wordlist, db_path)?.as_ref(), length)?;
+    if index.is_empty() {
+        return Err(WordleQueryError::QueryError(format!("The dictionary has no {}-letter words.", length)));
+    }
+    let mut game = Absurdle::new(index);
+
+    println!("The answer is any of {} words until you pin it down. Type quit to give up.", game.candidates().len());
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    while !game.is_solved() {
+        let Some(input) = prompt(&mut lines, &format!("{}> ", game.rows().len() + 1)) else { break };
+        match input.as_str() {
+            "" => continue,
+            "quit" | "exit" => break,
+            _ => {}
+        }
+        if let Err(e) = game.guess(&layout_fix(matches).word(&input)) {
+            eprintln!("{}", e);
+            continue;
+        }
+        println!();
+        print_board(game.rows());
+        if !game.is_solved() {
+            println!("Words remaining: {}", game.candidates().len());
+        }
+    }
+
+    if game.is_solved() {
+        println!("Solved in {}!", guess_count(game.rows().len()));
+    } else if let Some(word) = game.candidates().first() {
+        println!("One word it could still have been: {}", word.to_uppercase());
+    }
+    Ok(())
+}
+
+/// ANSI background colour for a tile or key
+fn tile(text: &str, colour: Option<Colour>) -> String {
+    let code = match colour {
+        Some(Colour::Green) => "30;42",
+        Some(Colour::Yellow) => "30;43",
+        Some(Colour::Grey) => "37;100",
+        None => return format!(" {} ", text),
+    };
+    format!("\x1b[{}m {} \x1b[0m", code, text)
+}
+
+/// Print every guess as coloured tiles followed by the keyboard with each letter's best colour
+fn print_board(rows: &[(String, Vec<Colour>)]) {
+    for (guess, colours) in rows {
+        let tiles: String = guess
+            .chars()
+            .zip(colours)
+            .map(|(c, &colour)| tile(&c.to_uppercase().to_string(), Some(colour)))
+            .collect();
+        println!("{}", tiles);
+    }
+    println!();
+    for (i, row) in game::KEYBOARD_ROWS.iter().enumerate() {
+        let keys: String = row.chars().map(|c| tile(&c.to_uppercase().to_string(), game::letter_state(rows, c))).collect();
+        println!("{}{}", " ".repeat(i), keys);
+    }
+    println!();
+}
+
+/// Handle `dict import`, writing the word list into the database at `db_path`
+fn import_dictionary(matches: &clap::ArgMatches, db_path: &str) -> Result<(), WordleQueryError> {
+    let input = PathBuf::from(matches.get_one::<String>("input").unwrap());
+    let format = match matches.get_one::<String>("format") {
+        Some(format) => format.parse()?,
+        None => DictFormat::from_path(&input),
+    };
+
+    let reader = io::BufReader::new(fs::File::open(&input)?);
+    let entries = dict::read_word_list(reader, format)?;
+    let mut conn = Connection::open(db_path)?;
+    let stats = dict::import_entries(&mut conn, entries)?;
+
+    println!("Read {} rows from {}", stats.read, input.display());
+    println!("Skipped {} rows that were not Russian words", stats.skipped);
+    println!("Merged {} duplicate rows", stats.duplicates);
+    println!("Imported {} words into {}", stats.imported, db_path);
+    Ok(())
+}
+
 fn main() -> rusqlite::Result<()> {
     let start = Instant::now();
 
@@ -73,6 +750,30 @@ fn main() -> rusqlite::Result<()> {
                 .action(clap::ArgAction::Append)
                 .num_args(1..),
         )
+        .arg(
+            Arg::new("guess")
+                .short('g')
+                .long("guess")
+                .value_name("GUESS:FEEDBACK")
+                .help("A guess and the colours shown for it, e.g. слово:GYBBG or слово:зжссз")
+                .required(false)
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("share")
+                .long("share")
+                .value_name("FILE")
+                .help("A pasted emoji share grid (🟩🟨⬜) to continue from; use - to read it from standard input")
+                .required(false),
+        )
+        .arg(
+            Arg::new("share_words")
+                .long("share-words")
+                .value_name("WORDS")
+                .help("The guessed words for the share grid rows, separated by commas or spaces")
+                .required(false)
+                .requires("share"),
+        )
         .arg(
             Arg::new("rejects")
                 .short('r')
@@ -86,14 +787,130 @@ fn main() -> rusqlite::Result<()> {
                 .value_name("LIMIT")
                 .help("Limit the number of words displayed")
                 .required(false)
+                .value_parser(clap::value_parser!(usize))
+                .global(true),
+        )
+        .arg(
+            Arg::new("strategy")
+                .long("strategy")
+                .value_name("STRATEGY")
+                .help("How to rank suggestions: frequency, entropy, minimax or expected")
+                .required(false)
+                .default_value("frequency")
+                .value_parser(["frequency", "entropy", "minimax", "expected"])
+                .global(true),
+        )
+        .arg(
+            Arg::new("entropy")
+                .long("entropy")
+                .help("Same as --strategy entropy")
+                .required(false)
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with("strategy")
+                .global(true),
+        )
+        .arg(
+            Arg::new("scorer")
+                .long("scorer")
+                .value_name("SCORER")
+                .help("How words are scored: frequency, unique, positional or logprob")
+                .required(false)
+                .default_value("frequency")
+                .value_parser(["frequency", "unique", "positional", "logprob"])
+                .global(true),
+        )
+        .arg(
+            Arg::new("freq_source")
+                .long("freq-source")
+                .value_name("SOURCE")
+                .help("Words the positional frequencies are computed from: candidates or dictionary")
+                .required(false)
+                .default_value("candidates")
+                .value_parser(["candidates", "dictionary"])
+                .global(true),
+        )
+        .arg(
+            Arg::new("hint")
+                .long("hint")
+                .value_name("KIND")
+                .help("Print a hint instead of the candidates: count, letter, repeat, pos or guess (or 1-5). Can be repeated.")
+                .required(false)
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("interactive")
+                .short('i')
+                .long("interactive")
+                .help("Start an interactive session that prompts for each guess and its feedback")
+                .required(false)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("length")
+                .long("length")
+                .value_name("LENGTH")
+                .help("Number of letters in the answer, from 1 to 20")
+                .required(false)
+                .default_value("5")
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..=MAX_WORD_LENGTH as u64))
+                .global(true),
+        )
+        .arg(
+            Arg::new("puzzle")
+                .long("puzzle")
+                .value_name("NUMBER")
+                .help("Puzzle number to show in the share text")
+                .required(false)
+                .value_parser(clap::value_parser!(u64))
+                .global(true),
+        )
+        .arg(
+            Arg::new("summary")
+                .long("summary")
+                .help("Share a spoiler-free one-line summary instead of the emoji grid")
+                .required(false)
+                .action(clap::ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            Arg::new("max_attempts")
+                .long("max-attempts")
+                .value_name("N")
+                .help("Number of guesses the puzzle allowed, shown in the share text")
+                .required(false)
+                .default_value("6")
                 .value_parser(clap::value_parser!(usize)),
         )
+        .arg(
+            Arg::new("hard")
+                .long("hard")
+                .help("The puzzle was played in hard mode, marked with * in the share text")
+                .required(false)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("no_layout_fix")
+                .long("no-layout-fix")
+                .help("Do not convert words typed with the keyboard on the English layout (e.g. cjkyw to солнц)")
+                .required(false)
+                .action(clap::ArgAction::SetTrue)
+                .global(true),
+        )
         .arg(
             Arg::new("dbpath")
                 .long("dbpath")
                 .value_name("DB_PATH")
                 .help("Path to the database")
-                .required(false),
+                .required(false)
+                .global(true),
+        )
+        .arg(
+            Arg::new("wordlist")
+                .long("wordlist")
+                .value_name("FILE")
+                .help("Read words from a plain-text, CSV or TSV word list instead of the database")
+                .required(false)
+                .global(true),
         )
         .arg(
             Arg::new("setdbpath")
@@ -109,6 +926,137 @@ fn main() -> rusqlite::Result<()> {
                 .required(false)
                 .action(clap::ArgAction::SetTrue),
         )
+        .subcommand(
+            Command::new("dict")
+                .about("Manage the word database")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("import")
+                        .about("Build the word database from a plain-text, CSV or TSV word list")
+                        .arg(
+                            Arg::new("input")
+                                .value_name("FILE")
+                                .help("Word list: one word per line, or CSV/TSV with word, frequency and pos columns")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::new("format")
+                                .long("format")
+                                .value_name("FORMAT")
+                                .help("Word list format; guessed from the file extension if omitted")
+                                .required(false)
+                                .value_parser(["plain", "csv", "tsv"]),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Play every word of an answer list with the chosen strategy and report how many guesses it took")
+                .arg(
+                    Arg::new("answers")
+                        .long("answers")
+                        .value_name("FILE")
+                        .help("Word list of answers to play; defaults to every word in the dictionary")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("max_guesses")
+                        .long("max-guesses")
+                        .value_name("N")
+                        .help("Guesses allowed before a game counts as failed")
+                        .required(false)
+                        .default_value("6")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    Arg::new("hardest")
+                        .long("hardest")
+                        .value_name("N")
+                        .help("Number of hardest words to list")
+                        .required(false)
+                        .default_value("10")
+                        .value_parser(clap::value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("play")
+                .about("Play Russian Wordle in the terminal against a secret word from the dictionary")
+                .args(game_args()),
+        )
+        .subcommand(
+            Command::new("daily")
+                .about("Play the daily puzzle, which has the same word for everyone using the same dictionary and salt")
+                .args(game_args())
+                .arg(
+                    Arg::new("date")
+                        .long("date")
+                        .value_name("YYYY-MM-DD")
+                        .help("Play the puzzle of another day; defaults to today (UTC)")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("salt")
+                        .long("salt")
+                        .value_name("SALT")
+                        .help("Shared secret mixed into the choice of word, so a team gets its own sequence")
+                        .required(false)
+                        .default_value(""),
+                )
+                .arg(
+                    Arg::new("window")
+                        .long("window")
+                        .value_name("DAYS")
+                        .help("Number of days within which an answer is not repeated")
+                        .required(false)
+                        .default_value("365")
+                        .value_parser(clap::value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("analyse")
+                .visible_alias("analyze")
+                .about("Rate each guess of a finished game against the best guess by expected information")
+                .arg(
+                    Arg::new("answer")
+                        .long("answer")
+                        .value_name("WORD")
+                        .help("The answer of the game")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("guesses")
+                        .value_name("GUESS")
+                        .help("The guesses played, in order")
+                        .required(true)
+                        .num_args(1..),
+                ),
+        )
+        .subcommand(
+            Command::new("multi")
+                .about("Solve several boards that share the same guesses, as in Dordle or Quordle")
+                .arg(
+                    Arg::new("boards")
+                        .long("boards")
+                        .value_name("N")
+                        .help("Number of boards")
+                        .required(false)
+                        .default_value("4")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    Arg::new("guess")
+                        .short('g')
+                        .long("guess")
+                        .value_name("GUESS:FEEDBACK/FEEDBACK")
+                        .help("A guess and the colours on each board, e.g. слово:GYBBB/BBBBB; use - for a solved board")
+                        .required(false)
+                        .action(clap::ArgAction::Append),
+                ),
+        )
+        .subcommand(
+            Command::new("absurdle")
+                .about("Play adversarial Wordle, where the answer dodges your guesses for as long as it can"),
+        )
         .get_matches();
 
     // Check if we are setting or removing the dbpath
@@ -126,35 +1074,97 @@ fn main() -> rusqlite::Result<()> {
     }
 
     // Load the DB path from the command line or config file
-    let db_path = if let Some(db_path) = matches.get_one::<String>("dbpath") {
-        db_path.to_string()
-    } else if let Some(stored_db_path) = load_config() {
-        stored_db_path
-    } else {
-        eprintln!("Error: No database path set. Use --setdbpath to set the database path.");
+    let db_path = matches.get_one::<String>("dbpath").cloned().or_else(load_config);
+
+    if let Some(("dict", dict_matches)) = matches.subcommand() {
+        if let Some(("import", import_matches)) = dict_matches.subcommand() {
+            let Some(db_path) = db_path else {
+                eprintln!("Error: No database path set. Use --dbpath to choose where to build the database.");
+                std::process::exit(1);
+            };
+            if let Err(e) = import_dictionary(import_matches, &db_path) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
         return Ok(());
-    };
+    }
+
+    if let Some((name @ ("play" | "daily"), play_matches)) = matches.subcommand() {
+        if let Err(e) = run_play(play_matches, db_path.as_deref(), name == "daily") {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(("analyse", analyse_matches)) = matches.subcommand() {
+        if let Err(e) = run_analysis(analyse_matches, db_path.as_deref()) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(("multi", multi_matches)) = matches.subcommand() {
+        if let Err(e) = run_multi(multi_matches, db_path.as_deref()) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(("absurdle", absurdle_matches)) = matches.subcommand() {
+        if let Err(e) = run_absurdle(absurdle_matches, db_path.as_deref()) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(("bench", bench_matches)) = matches.subcommand() {
+        if let Err(e) = run_bench(bench_matches, db_path.as_deref()) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-    // Ensure pattern is provided when neither setdbpath nor remove_dbpath is provided
-    if !matches.contains_id("pattern") {
-        eprintln!("Error: --pattern is required unless setting or removing the database path.");
+    // Ensure a pattern or guess is provided when neither setdbpath nor remove_dbpath is provided
+    let interactive = matches.get_flag("interactive");
+    if !interactive && !matches.contains_id("pattern") && !matches.contains_id("guess") && !matches.contains_id("share") {
+        eprintln!("Error: --pattern, --guess or --share is required unless setting or removing the database path.");
         return Ok(());
     }
 
+    // Text typed with the keyboard on the English layout is retyped before it is parsed
+    let layout = layout_fix(&matches);
     let patterns: Vec<String> = matches
         .get_many::<String>("pattern")
         .unwrap_or_default()
-        .map(|s| s.to_string())
+        .map(|s| layout.word(s))
+        .collect();
+    let guesses: Vec<String> = matches
+        .get_many::<String>("guess")
+        .unwrap_or_default()
+        .map(|s| layout.guess_row(s))
         .collect();
 
-    println!("Using database path: {}", db_path);
+    match (matches.get_one::<String>("wordlist"), &db_path) {
+        (Some(wordlist), _) => println!("Using word list: {}", wordlist),
+        (None, Some(db_path)) => println!("Using database path: {}", db_path),
+        (None, None) => println!("Using the embedded word list"),
+    }
     println!("Using patterns: {:?}", patterns);
+    if !guesses.is_empty() {
+        println!("Using guesses: {:?}", guesses);
+    }
 
-    let patterns: Vec<String> = matches.get_many::<String>("pattern").unwrap().cloned().collect();
+    let length = *matches.get_one::<usize>("length").unwrap_or(&WORD_LENGTH);
     let mut pattern_lengths_valid = true;
 
     for pattern in &patterns {
-        if !is_valid_pattern(pattern) {
+        if !is_valid_pattern_of_length(pattern, length) {
             eprintln!("Error: Incorrect pattern format");
             pattern_lengths_valid = false;
         }
@@ -164,39 +1174,32 @@ fn main() -> rusqlite::Result<()> {
         std::process::exit(1);
     }
 
-    let mut all_rejects: Vec<char> = vec![];
-    let mut validated_patterns = vec![];
-    for pattern in &patterns {
-        let (modified_pattern, extracted_rejects) = parse_pattern(pattern);
-        all_rejects.extend(extracted_rejects); // Collect all extracted rejects
-        validated_patterns.push(modified_pattern);
-    }
-    let rejects = matches.get_one::<String>("rejects").map(String::as_str).unwrap_or("");
-    // Add additional rejects provided via --rejects
-    all_rejects.extend(rejects.chars());
-
-    let rejects_string: String = all_rejects.iter().collect();
+    // Grey `_x` cells are resolved by WordleQuery so duplicates of found letters are not rejected
+    let mut validated_patterns = patterns.clone();
+    let rejects_string = &layout.rejects(matches.get_one::<String>("rejects").map(String::as_str).unwrap_or(""));
 
     let limit = *matches.get_one::<usize>("limit").unwrap_or(&10); 
-    let conn = Connection::open(db_path)?;
-
-    let mut results = None;
+    let wordlist = matches.get_one::<String>("wordlist");
+    // Load the vocabulary once so every query and ranking pass is answered from memory.
+    // The source stays open for what the index does not keep, such as parts of speech.
+    let loaded = open_source(wordlist.map(String::as_str), db_path.as_deref())
+        .and_then(|source| WordIndex::from_source(source.as_ref(), length).map(|index| (source, index)));
+    let (source, index) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Ok(());
+        }
+    };
 
+    // Accumulate every pattern and guess row into a single constraint set
+    let mut constraints = Constraints::new(length);
+    if validated_patterns.is_empty() {
+        validated_patterns.push("*".repeat(length));
+    }
     for pattern in validated_patterns {
-        match WordleQuery::new(&pattern, &rejects_string) {
-            Ok(wordle_query) => {
-                let query = wordle_query.build_query();
-                let words = load_words_from_query(&query, &conn)?;
-
-                results = match results {
-                    None => Some(words),
-                    Some(existing_results) => Some(existing_results.intersection(&words).cloned().collect()),
-                };
-
-                if results.as_ref().unwrap().is_empty() {
-                    break;
-                }
-            }
+        match WordleQuery::with_length(&pattern, rejects_string, length) {
+            Ok(wordle_query) => constraints.merge(&wordle_query.constraints),
             Err(e) => {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
@@ -204,25 +1207,71 @@ fn main() -> rusqlite::Result<()> {
         }
     }
 
-    let mut wordles: Vec<Wordle> = if let Some(words) = results {
-        words.into_iter().map(Wordle::new).collect()
-    } else {
-        Vec::new()
+    let strategy = strategy(&matches).unwrap();
+    let score = score_kind(&matches);
+    let puzzle = matches.get_one::<u64>("puzzle").copied();
+    let hints = match matches.get_many::<String>("hint").unwrap_or_default().map(|kind| kind.parse()).collect() {
+        Ok(hints) => hints,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let options = SuggestOptions {
+        strategy,
+        score,
+        length,
+        limit,
+        puzzle,
+        summary: matches.get_flag("summary"),
+        hints,
+        max_attempts: *matches.get_one::<usize>("max_attempts").unwrap_or(&game::DEFAULT_ATTEMPTS),
+        hard_mode: matches.get_flag("hard"),
+        layout,
     };
 
-    wordles.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-
-    if limit > 0 && wordles.len() > limit {
-        wordles.truncate(limit);
+    // In interactive mode the command-line guesses become session rows so they can be undone.
+    // A share grid comes from earlier in the game, so its rows go before the `-g` guesses.
+    let mut session = Session::new(constraints.clone());
+    let rows = load_share_rows(&matches).and_then(|mut rows| {
+        for guess in &guesses {
+            rows.push(parse_guess(guess)?);
+        }
+        Ok(rows)
+    });
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    for (word, feedback) in &rows {
+        let result = if interactive {
+            session.add_guess(word, feedback)
+        } else {
+            constraints.add_guess(word, feedback)
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
     }
 
-    let mut table = Table::new();
-    table.add_row(row!["lemma", "score"]);
-    for wordle in &wordles {
-        table.add_row(row![wordle.lemma, wordle.score as u64]);
+    let result = if interactive {
+        run_interactive(session, &options, &index, source.as_ref())
+    } else if print_solved(&rows, &options) {
+        Ok(())
+    } else {
+        print_results(&options, constraints, &index, source.as_ref())
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+    if interactive {
+        return Ok(());
     }
-
-    table.printstd();
 
     let duration = start.elapsed();
     println!("Elapsed time: {:.3}s", duration.as_secs_f64());
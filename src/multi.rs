@@ -0,0 +1,159 @@
+//! Solving several boards that share the same guesses, as in Dordle and Quordle
+
+use std::collections::HashSet;
+
+use crate::solver::expected_information;
+use crate::source::WordSource;
+use crate::{normalize_guess_word, parse_feedback, split_guess_row, Colour, Constraints, WordleQueryError};
+
+/// Feedback for one guess on every board, `None` for boards that are already solved
+pub type BoardFeedback = Vec<Option<Vec<Colour>>>;
+
+// Struct for the boards of one multi-board game, each with its own constraints
+#[derive(Debug, Clone)]
+pub struct MultiBoard {
+    boards: Vec<Constraints>,
+    solved: Vec<bool>,
+}
+
+impl MultiBoard {
+    pub fn new(count: usize, length: usize) -> Self {
+        MultiBoard { boards: vec![Constraints::new(length); count], solved: vec![false; count] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.boards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boards.is_empty()
+    }
+
+    pub fn board(&self, i: usize) -> &Constraints {
+        &self.boards[i]
+    }
+
+    pub fn is_solved(&self, i: usize) -> bool {
+        self.solved[i]
+    }
+
+    pub fn all_solved(&self) -> bool {
+        self.solved.iter().all(|&solved| solved)
+    }
+
+    /// Apply one guess to every board. Boards that are already solved show no feedback and take `None`;
+    /// an all-green row marks its board as solved. Nothing changes if any board rejects the row.
+    pub fn add_guess(&mut self, guess: &str, feedback: &[Option<Vec<Colour>>]) -> Result<(), WordleQueryError> {
+        if feedback.len() != self.boards.len() {
+            return Err(WordleQueryError::QueryError(format!(
+                "Expected feedback for {} boards, got {}.",
+                self.boards.len(),
+                feedback.len()
+            )));
+        }
+
+        let mut boards = self.boards.clone();
+        let mut solved = self.solved.clone();
+        for (i, colours) in feedback.iter().enumerate() {
+            match colours {
+                _ if self.solved[i] => {}
+                Some(colours) => {
+                    boards[i].add_guess(guess, colours)?;
+                    solved[i] = colours.iter().all(|&c| c == Colour::Green);
+                }
+                None => {
+                    return Err(WordleQueryError::QueryError(format!("Board {} needs feedback.", i + 1)));
+                }
+            }
+        }
+        self.boards = boards;
+        self.solved = solved;
+        Ok(())
+    }
+
+    /// Candidates for every board, sorted; solved boards have none left to find
+    pub fn candidates(&self, source: &dyn WordSource) -> Result<Vec<Vec<String>>, WordleQueryError> {
+        self.boards
+            .iter()
+            .zip(&self.solved)
+            .map(|(constraints, &solved)| {
+                if solved {
+                    return Ok(Vec::new());
+                }
+                let mut words: Vec<String> = source.candidates(constraints)?.into_iter().collect();
+                words.sort();
+                Ok(words)
+            })
+            .collect()
+    }
+}
+
+/// Split a row such as `слово:GYBBB/BBBBB/-/BBBBB` into the word and the feedback for each board,
+/// with the same separators as `parse_guess`.
+/// A `-` stands for a board that is already solved.
+pub fn parse_multi_guess(input: &str, boards: usize) -> Result<(String, BoardFeedback), WordleQueryError> {
+    let (word, rows) = split_guess_row(input).ok_or_else(|| {
+        WordleQueryError::QueryError(format!("Guess '{}' must look like WORD:FEEDBACK/FEEDBACK, e.g. слово:GYBBG/BBBBB.", input))
+    })?;
+    let feedback = rows
+        .split('/')
+        .map(str::trim)
+        .map(|row| if row == "-" { Ok(None) } else { parse_feedback(row).map(Some) })
+        .collect::<Result<Vec<_>, _>>()?;
+    if feedback.len() != boards {
+        return Err(WordleQueryError::QueryError(format!(
+            "Guess '{}' has feedback for {} boards, expected {}.",
+            input,
+            feedback.len(),
+            boards
+        )));
+    }
+    Ok((normalize_guess_word(word), feedback))
+}
+
+// Struct for a guess ranked across all boards
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiRanking {
+    pub guess: String,
+    /// Expected information summed over the unsolved boards
+    pub bits: f64,
+    /// Expected information on each board, zero for solved boards
+    pub board_bits: Vec<f64>,
+    /// Boards on which the guess could be the answer
+    pub candidate_boards: usize,
+    /// Boards on which the guess is the only candidate left
+    pub solves: usize,
+}
+
+/// Rank guesses by combined information over every board's candidates, best first.
+/// A guess that is the last candidate on some board comes first, since playing it finishes that board;
+/// ties go to guesses that could be the answer on more boards.
+pub fn rank_multi_guesses(guesses: &[String], candidates: &[Vec<String>]) -> Vec<MultiRanking> {
+    let candidate_chars: Vec<Vec<Vec<char>>> =
+        candidates.iter().map(|words| words.iter().map(|w| w.chars().collect()).collect()).collect();
+    let candidate_sets: Vec<HashSet<&str>> =
+        candidates.iter().map(|words| words.iter().map(String::as_str).collect()).collect();
+
+    let mut rankings: Vec<MultiRanking> = guesses
+        .iter()
+        .map(|guess| {
+            let letters: Vec<char> = guess.chars().collect();
+            let board_bits: Vec<f64> = candidate_chars
+                .iter()
+                .map(|words| if words.is_empty() { 0.0 } else { expected_information(&letters, words) })
+                .collect();
+            let candidate_boards = candidate_sets.iter().filter(|set| set.contains(guess.as_str())).count();
+            let solves = candidate_sets.iter().filter(|set| set.len() == 1 && set.contains(guess.as_str())).count();
+            MultiRanking { guess: guess.clone(), bits: board_bits.iter().sum(), board_bits, candidate_boards, solves }
+        })
+        .collect();
+
+    rankings.sort_by(|a, b| {
+        b.solves
+            .cmp(&a.solves)
+            .then(b.bits.total_cmp(&a.bits))
+            .then(b.candidate_boards.cmp(&a.candidate_boards))
+            .then(a.guess.cmp(&b.guess))
+    });
+    rankings
+}
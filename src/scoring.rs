@@ -0,0 +1,196 @@
+//! Pluggable word scoring used to order suggestions
+
+use std::collections::{HashMap, HashSet};
+
+use crate::Wordle;
+
+/// Frequency (in percent) used for letters missing from a frequency table
+pub const UNKNOWN_LETTER_FREQ: f64 = 0.01;
+
+/// Something that can rank words, higher scores first
+pub trait Scorer {
+    /// Score a single word
+    fn score(&self, word: &str) -> f64;
+
+    /// Render a score for the suggestion table
+    fn display(&self, score: f64) -> String {
+        format!("{}", score as i64)
+    }
+}
+
+// Struct for the product of general Russian letter frequencies
+#[derive(Debug, Clone)]
+pub struct FrequencyProduct {
+    freqs: HashMap<char, f64>,
+}
+
+impl FrequencyProduct {
+    pub fn new(freqs: HashMap<char, f64>) -> Self {
+        FrequencyProduct { freqs }
+    }
+}
+
+impl Default for FrequencyProduct {
+    fn default() -> Self {
+        Self::new(Wordle::init_letter_freqs())
+    }
+}
+
+impl Scorer for FrequencyProduct {
+    fn score(&self, word: &str) -> f64 {
+        Wordle::calculate_score(word, &self.freqs)
+    }
+}
+
+// Struct for scoring that only counts each distinct letter once
+#[derive(Debug, Clone)]
+pub struct UniqueLetters {
+    freqs: HashMap<char, f64>,
+}
+
+impl UniqueLetters {
+    pub fn new(freqs: HashMap<char, f64>) -> Self {
+        UniqueLetters { freqs }
+    }
+}
+
+impl Default for UniqueLetters {
+    fn default() -> Self {
+        Self::new(Wordle::init_letter_freqs())
+    }
+}
+
+impl Scorer for UniqueLetters {
+    /// Sum of the frequencies of the distinct letters, so repeats add nothing
+    fn score(&self, word: &str) -> f64 {
+        let letters: HashSet<char> = word.chars().collect();
+        letters.iter().map(|c| self.freqs.get(c).copied().unwrap_or(UNKNOWN_LETTER_FREQ)).sum()
+    }
+}
+
+// Struct for the log-probability of a word under independent letter frequencies
+#[derive(Debug, Clone)]
+pub struct LogProbability {
+    freqs: HashMap<char, f64>,
+}
+
+impl LogProbability {
+    pub fn new(freqs: HashMap<char, f64>) -> Self {
+        LogProbability { freqs }
+    }
+}
+
+impl Default for LogProbability {
+    fn default() -> Self {
+        Self::new(Wordle::init_letter_freqs())
+    }
+}
+
+impl Scorer for LogProbability {
+    /// Natural log of the product of letter probabilities, which does not overflow for long words
+    fn score(&self, word: &str) -> f64 {
+        word.chars()
+            .map(|c| (self.freqs.get(&c).copied().unwrap_or(UNKNOWN_LETTER_FREQ) / 100.0).ln())
+            .sum()
+    }
+
+    fn display(&self, score: f64) -> String {
+        format!("{:.3}", score)
+    }
+}
+
+// Struct for per-position letter frequencies
+#[derive(Debug, Clone, Default)]
+pub struct PositionalFrequencies {
+    /// Letter counts for each position
+    counts: Vec<HashMap<char, usize>>,
+    /// Number of words counted
+    total: usize,
+}
+
+impl PositionalFrequencies {
+    /// Count the letters at each position over a set of words, skipping words of other lengths
+    pub fn from_words<'a>(words: impl IntoIterator<Item = &'a str>, length: usize) -> Self {
+        let mut counts = vec![HashMap::new(); length];
+        let mut total = 0;
+        for word in words {
+            let letters: Vec<char> = word.chars().collect();
+            if letters.len() != length {
+                continue;
+            }
+            for (position, c) in letters.into_iter().enumerate() {
+                *counts[position].entry(c).or_insert(0) += 1;
+            }
+            total += 1;
+        }
+        PositionalFrequencies { counts, total }
+    }
+
+    /// Number of words the frequencies were computed from
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Percentage of counted words with `c` at `position`
+    pub fn frequency(&self, position: usize, c: char) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let count = self.counts.get(position).and_then(|counts| counts.get(&c)).copied().unwrap_or(0);
+        count as f64 * 100.0 / self.total as f64
+    }
+
+    /// Most common letter at a position, with its percentage
+    pub fn most_common(&self, position: usize) -> Option<(char, f64)> {
+        self.counts
+            .get(position)?
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(&c, _)| (c, self.frequency(position, c)))
+    }
+}
+
+impl Scorer for PositionalFrequencies {
+    /// Sum of how common each letter of the word is at its position
+    fn score(&self, word: &str) -> f64 {
+        word.chars().enumerate().map(|(position, c)| self.frequency(position, c)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positional_frequencies_count_each_position() {
+        let freqs = PositionalFrequencies::from_words(["мачта", "шахта", "вахта", "слон"], 5);
+        assert_eq!(freqs.total(), 3);
+        assert_eq!(freqs.frequency(4, 'а'), 100.0);
+        assert_eq!(freqs.frequency(2, 'х'), 200.0 / 3.0);
+        assert_eq!(freqs.frequency(0, 'я'), 0.0);
+        assert_eq!(freqs.most_common(3), Some(('т', 100.0)));
+    }
+
+    #[test]
+    fn test_unique_letters_ignores_repeats() {
+        let scorer = UniqueLetters::default();
+        assert_eq!(scorer.score("ооооо"), scorer.score("о"));
+        assert!(scorer.score("сонет") > scorer.score("ососо"));
+    }
+
+    #[test]
+    fn test_log_probability_orders_like_product() {
+        let product = FrequencyProduct::default();
+        let log = LogProbability::default();
+        assert!(product.score("сонет") > product.score("щуфьъ"));
+        assert!(log.score("сонет") > log.score("щуфьъ"));
+        assert!(log.score("сонет") < 0.0);
+    }
+
+    #[test]
+    fn test_positional_score_prefers_typical_shape() {
+        let freqs = PositionalFrequencies::from_words(["мачта", "шахта", "вахта"], 5);
+        assert!(freqs.score("вахта") > freqs.score("мачта"));
+        assert_eq!(PositionalFrequencies::default().score("вахта"), 0.0);
+    }
+}
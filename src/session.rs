@@ -0,0 +1,52 @@
+//! State for an interactive solving session
+
+use crate::{Colour, Constraints, WordleQueryError};
+
+// Struct for the guesses entered so far in an interactive session
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// Constraints given up front, e.g. from `--pattern` and `--rejects`
+    base: Constraints,
+    /// Guess rows in the order they were entered
+    rows: Vec<(String, Vec<Colour>)>,
+}
+
+impl Session {
+    /// Start a session on top of constraints that stay in place across `reset`
+    pub fn new(base: Constraints) -> Self {
+        Session { base, rows: Vec::new() }
+    }
+
+    /// Record a guess and its feedback, rejecting rows that do not fit the word length
+    pub fn add_guess(&mut self, guess: &str, feedback: &[Colour]) -> Result<(), WordleQueryError> {
+        // Validate against a scratch copy so a bad row leaves the session untouched
+        self.constraints().add_guess(guess, feedback)?;
+        self.rows.push((guess.to_string(), feedback.to_vec()));
+        Ok(())
+    }
+
+    /// Remove the most recent guess, returning it
+    pub fn undo(&mut self) -> Option<(String, Vec<Colour>)> {
+        self.rows.pop()
+    }
+
+    /// Forget every guess entered so far
+    pub fn reset(&mut self) {
+        self.rows.clear();
+    }
+
+    /// Guess rows entered so far
+    pub fn rows(&self) -> &[(String, Vec<Colour>)] {
+        &self.rows
+    }
+
+    /// Combined constraints from the base and every guess row
+    pub fn constraints(&self) -> Constraints {
+        let mut constraints = self.base.clone();
+        for (guess, feedback) in &self.rows {
+            // Rows were validated when they were added
+            constraints.add_guess(guess, feedback).unwrap();
+        }
+        constraints
+    }
+}
@@ -0,0 +1,156 @@
+//! Reading and writing the emoji grids people paste into chat after a game
+
+use crate::{guess_count, normalize_guess_word, Colour, WordleQueryError};
+
+/// Variation selector that often follows `⬜` and `⬛` in pasted text
+const VARIATION_SELECTOR: char = '\u{FE0F}';
+
+// Struct for one row of a share grid, with the guessed word if it was written next to the tiles
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareRow {
+    pub colours: Vec<Colour>,
+    pub word: Option<String>,
+}
+
+/// Colour of a share tile. Orange and blue are the high-contrast green and yellow.
+fn tile_colour(c: char) -> Option<Colour> {
+    match c {
+        '🟩' | '🟧' => Some(Colour::Green),
+        '🟨' | '🟦' => Some(Colour::Yellow),
+        '⬜' | '⬛' => Some(Colour::Grey),
+        _ => None,
+    }
+}
+
+/// Read the tile rows of a pasted share block. Lines without tiles, such as the
+/// `Wordle 123 4/6` header, are skipped. A word after the tiles on the same line is kept.
+pub fn parse_share_grid(text: &str) -> Result<Vec<ShareRow>, WordleQueryError> {
+    let mut rows = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        let tiles: String = line.chars().take_while(|&c| tile_colour(c).is_some() || c == VARIATION_SELECTOR).collect();
+        let colours: Vec<Colour> = tiles.chars().filter_map(tile_colour).collect();
+        if colours.is_empty() {
+            continue;
+        }
+
+        let rest = line[tiles.len()..].trim();
+        if rest.chars().any(|c| tile_colour(c).is_some()) {
+            return Err(WordleQueryError::QueryError(format!("Share row '{}' mixes tiles with other text.", line)));
+        }
+        let word = (!rest.is_empty()).then(|| normalize_guess_word(rest));
+        rows.push(ShareRow { colours, word });
+    }
+
+    if rows.is_empty() {
+        return Err(WordleQueryError::QueryError("The share text has no rows of 🟩🟨⬜ tiles.".to_string()));
+    }
+    if let Some(row) = rows.iter().find(|row| row.colours.len() != rows[0].colours.len()) {
+        return Err(WordleQueryError::QueryError(format!(
+            "Share rows must all have {} tiles, found one with {}.",
+            rows[0].colours.len(),
+            row.colours.len()
+        )));
+    }
+    Ok(rows)
+}
+
+/// Pair each share row with its guessed word. Rows that carry their own word keep it;
+/// the others take the given words in order, which must be exactly enough to go round.
+pub fn share_guesses(rows: &[ShareRow], words: &[String]) -> Result<Vec<(String, Vec<Colour>)>, WordleQueryError> {
+    let missing = rows.iter().filter(|row| row.word.is_none()).count();
+    if missing != words.len() {
+        return Err(WordleQueryError::QueryError(format!(
+            "The share grid needs {} guessed words but {} were given.",
+            missing,
+            words.len()
+        )));
+    }
+
+    let mut words = words.iter().map(|word| normalize_guess_word(word));
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let word = row.word.clone().or_else(|| words.next()).unwrap_or_default();
+            (word, row.colours.clone())
+        })
+        .collect())
+}
+
+/// Title used in share text when none is given
+pub const DEFAULT_TITLE: &str = "Wordle";
+
+/// Emoji the game uses for a tile
+pub fn tile_emoji(colour: Colour) -> char {
+    match colour {
+        Colour::Green => '🟩',
+        Colour::Yellow => '🟨',
+        Colour::Grey => '⬜',
+    }
+}
+
+// Struct for the result of a finished game, ready to be shared
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareCard {
+    pub title: String,
+    pub puzzle: Option<u64>,
+    pub rows: Vec<Vec<Colour>>,
+    pub max_attempts: usize,
+    pub hard_mode: bool,
+}
+
+impl ShareCard {
+    pub fn new(rows: Vec<Vec<Colour>>, max_attempts: usize) -> Self {
+        ShareCard { title: DEFAULT_TITLE.to_string(), puzzle: None, rows, max_attempts, hard_mode: false }
+    }
+
+    pub fn with_puzzle(mut self, puzzle: Option<u64>) -> Self {
+        self.puzzle = puzzle;
+        self
+    }
+
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Whether the last row is all green
+    pub fn solved(&self) -> bool {
+        self.rows.last().is_some_and(|row| row.iter().all(|&c| c == Colour::Green))
+    }
+
+    /// Guesses used out of the attempts, `X` for a lost game, with `*` for hard mode
+    pub fn score(&self) -> String {
+        let used = if self.solved() { self.rows.len().to_string() } else { "X".to_string() };
+        let hard = if self.hard_mode { "*" } else { "" };
+        format!("{}/{}{}", used, self.max_attempts, hard)
+    }
+
+    fn heading(&self) -> String {
+        match self.puzzle {
+            Some(puzzle) => format!("{} {}", self.title, puzzle),
+            None => self.title.clone(),
+        }
+    }
+
+    /// The standard share text: heading, score and one row of tiles per guess
+    pub fn text(&self) -> String {
+        let mut text = format!("{} {}\n", self.heading(), self.score());
+        for row in &self.rows {
+            text.push('\n');
+            text.extend(row.iter().map(|&colour| tile_emoji(colour)));
+        }
+        text
+    }
+
+    /// One line with the result and no tiles, so it gives nothing away about the answer
+    pub fn summary(&self) -> String {
+        let mode = if self.hard_mode { " in hard mode" } else { "" };
+        let allowed = guess_count(self.max_attempts);
+        if self.solved() {
+            format!("{}: solved in {} of {}{}.", self.heading(), self.rows.len(), allowed, mode)
+        } else {
+            format!("{}: not solved in {}{}.", self.heading(), allowed, mode)
+        }
+    }
+}
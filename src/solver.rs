@@ -0,0 +1,247 @@
+//! Guess ranking based on how each guess splits the remaining candidates
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::scoring::{FrequencyProduct, Scorer};
+use crate::{Colour, Strategy, WordleQueryError, MAX_WORD_LENGTH};
+
+/// Colours the game shows for `guess` against `answer`, position by position.
+/// Duplicate letters are scored the way the game does: greens are matched first, then
+/// yellows are handed out left to right while unmatched copies remain in the answer.
+pub fn feedback_colours(guess: &[char], answer: &[char]) -> Vec<Colour> {
+    let mut colours = vec![Colour::Grey; guess.len()];
+    let mut unmatched: HashMap<char, usize> = HashMap::new();
+
+    for (i, (&g, &a)) in guess.iter().zip(answer).enumerate() {
+        if g == a {
+            colours[i] = Colour::Green;
+        } else {
+            *unmatched.entry(a).or_insert(0) += 1;
+        }
+    }
+
+    for (i, &g) in guess.iter().enumerate() {
+        if colours[i] == Colour::Green {
+            continue;
+        }
+        if let Some(count) = unmatched.get_mut(&g) {
+            if *count > 0 {
+                *count -= 1;
+                colours[i] = Colour::Yellow;
+            }
+        }
+    }
+
+    colours
+}
+
+/// Encode the colours the game would show for `guess` against `answer` as a base-3 number,
+/// with grey = 0, yellow = 1 and green = 2 for each position.
+///
+/// # Panics
+///
+/// If the words are longer than `MAX_WORD_LENGTH` letters, since their codes would not fit in a `u32`.
+pub fn feedback_code(guess: &[char], answer: &[char]) -> u32 {
+    pack_feedback(&feedback_colours(guess, answer))
+}
+
+/// Pack colours into the base-3 code used by `feedback_code`.
+/// Codes fit in a `u32` for up to `MAX_WORD_LENGTH` colours; longer feedback is an error.
+pub fn encode_feedback(colours: &[Colour]) -> Result<u32, WordleQueryError> {
+    if colours.len() > MAX_WORD_LENGTH {
+        return Err(WordleQueryError::QueryError(format!(
+            "Feedback for {} letters cannot be encoded; words may have at most {} letters.",
+            colours.len(),
+            MAX_WORD_LENGTH
+        )));
+    }
+    Ok(pack_feedback(colours))
+}
+
+fn pack_feedback(colours: &[Colour]) -> u32 {
+    // Longer feedback would wrap around and share its code with other feedback
+    assert!(colours.len() <= MAX_WORD_LENGTH, "feedback code overflows for {} letters", colours.len());
+    colours.iter().fold(0, |code, colour| {
+        let digit = match colour {
+            Colour::Grey => 0,
+            Colour::Yellow => 1,
+            Colour::Green => 2,
+        };
+        code * 3 + digit
+    })
+}
+
+/// Turn a base-3 feedback code back into the colours for each position
+pub fn decode_feedback(code: u32, length: usize) -> Vec<Colour> {
+    let mut colours = vec![Colour::Grey; length];
+    let mut code = code;
+    for colour in colours.iter_mut().rev() {
+        *colour = match code % 3 {
+            2 => Colour::Green,
+            1 => Colour::Yellow,
+            _ => Colour::Grey,
+        };
+        code /= 3;
+    }
+    colours
+}
+
+/// Count how many candidates fall into each feedback pattern for a guess.
+/// The patterns are kept in code order so sums over them come out the same on every run.
+pub fn partition_sizes(guess: &[char], candidates: &[Vec<char>]) -> BTreeMap<u32, usize> {
+    let mut sizes = BTreeMap::new();
+    for answer in candidates {
+        *sizes.entry(feedback_code(guess, answer)).or_insert(0) += 1;
+    }
+    sizes
+}
+
+/// Expected information in bits gained by playing `guess` when every candidate is equally likely
+pub fn expected_information(guess: &[char], candidates: &[Vec<char>]) -> f64 {
+    let total = candidates.len() as f64;
+    partition_sizes(guess, candidates)
+        .values()
+        .map(|&size| {
+            let p = size as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+// Struct for a ranked guess
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuessRanking {
+    pub guess: String,
+    /// Score of the guess from the `Scorer` used for ranking
+    pub score: f64,
+    /// Expected information in bits
+    pub bits: f64,
+    /// Largest number of candidates that can remain after the feedback
+    pub worst_case: usize,
+    /// Average number of candidates remaining after the feedback
+    pub expected_remaining: f64,
+    /// Whether the guess could itself be the answer
+    pub is_candidate: bool,
+}
+
+impl GuessRanking {
+    /// Measure how a guess splits the candidates
+    pub fn evaluate(guess: &str, candidates: &[Vec<char>], is_candidate: bool) -> Self {
+        let letters: Vec<char> = guess.chars().collect();
+        let sizes = partition_sizes(&letters, candidates);
+        let total = candidates.len() as f64;
+
+        let mut bits = 0.0;
+        let mut worst_case = 0;
+        let mut expected_remaining = 0.0;
+        for &size in sizes.values() {
+            let p = size as f64 / total;
+            bits -= p * p.log2();
+            worst_case = worst_case.max(size);
+            expected_remaining += p * size as f64;
+        }
+
+        GuessRanking { guess: guess.to_string(), score: 0.0, bits, worst_case, expected_remaining, is_candidate }
+    }
+}
+
+/// Rank every guess against the candidates using the given strategy, best first.
+/// Ties are broken in favour of guesses that could still be the answer.
+pub fn rank_guesses(strategy: Strategy, scorer: &dyn Scorer, guesses: &[String], candidates: &[String]) -> Vec<GuessRanking> {
+    let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    let candidate_chars: Vec<Vec<char>> = candidates.iter().map(|w| w.chars().collect()).collect();
+
+    let mut rankings: Vec<GuessRanking> = guesses
+        .iter()
+        .map(|guess| GuessRanking {
+            score: scorer.score(guess),
+            ..GuessRanking::evaluate(guess, &candidate_chars, candidate_set.contains(guess.as_str()))
+        })
+        .collect();
+
+    rankings.sort_by(|a, b| {
+        let order = match strategy {
+            Strategy::Frequency => b.score.total_cmp(&a.score),
+            Strategy::Entropy => b.bits.total_cmp(&a.bits),
+            Strategy::Minimax => a.worst_case.cmp(&b.worst_case)
+                .then(a.expected_remaining.total_cmp(&b.expected_remaining)),
+            Strategy::ExpectedRemaining => a.expected_remaining.total_cmp(&b.expected_remaining)
+                .then(a.worst_case.cmp(&b.worst_case)),
+        };
+        order
+            .then(b.is_candidate.cmp(&a.is_candidate))
+            .then(a.guess.cmp(&b.guess))
+    });
+    rankings
+}
+
+/// The single guess the strategy would play next. The frequency strategy picks the best-scoring
+/// candidate; the others may play any dictionary word until only two candidates are left.
+pub fn best_guess(strategy: Strategy, scorer: &dyn Scorer, dictionary: &[String], candidates: &[String]) -> Option<String> {
+    match strategy {
+        Strategy::Frequency => candidates
+            .iter()
+            .map(|word| (scorer.score(word), word))
+            .max_by(|a, b| a.0.total_cmp(&b.0).then(b.1.cmp(a.1)))
+            .map(|(_, word)| word.clone()),
+        strategy => {
+            let guesses = if candidates.len() > 2 { dictionary } else { candidates };
+            rank_guesses(strategy, scorer, guesses, candidates).into_iter().next().map(|r| r.guess)
+        }
+    }
+}
+
+/// Rank every guess by expected information over the candidates, best first
+pub fn rank_by_information(guesses: &[String], candidates: &[String]) -> Vec<GuessRanking> {
+    rank_guesses(Strategy::Entropy, &FrequencyProduct::default(), guesses, candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    #[test]
+    fn test_feedback_code_all_green() {
+        assert_eq!(feedback_code(&chars("слово"), &chars("слово")), 242);
+    }
+
+    #[test]
+    fn test_feedback_code_duplicate_letters() {
+        // Only one "с" in "слово", so the second "с" of "сосна" is grey
+        let code = feedback_code(&chars("сосна"), &chars("слово"));
+        assert_eq!(code, 2 * 81 + 27);
+    }
+
+    #[test]
+    fn test_decode_feedback_round_trip() {
+        let code = feedback_code(&chars("карта"), &chars("жираф"));
+        assert_eq!(decode_feedback(code, 5), feedback_colours(&chars("карта"), &chars("жираф")));
+        assert_eq!(encode_feedback(&decode_feedback(code, 5)).unwrap(), code);
+    }
+
+    #[test]
+    fn test_encode_feedback_up_to_max_word_length() {
+        let greens = vec![Colour::Green; MAX_WORD_LENGTH];
+        assert_eq!(encode_feedback(&greens).unwrap(), 3u32.pow(MAX_WORD_LENGTH as u32) - 1);
+        assert_eq!(decode_feedback(3u32.pow(MAX_WORD_LENGTH as u32) - 1, MAX_WORD_LENGTH), greens);
+        assert!(encode_feedback(&[Colour::Grey; MAX_WORD_LENGTH + 1]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "feedback code overflows")]
+    fn test_feedback_code_too_long() {
+        let word = vec!['а'; MAX_WORD_LENGTH + 1];
+        feedback_code(&word, &word);
+    }
+
+    #[test]
+    fn test_expected_information_of_perfect_split() {
+        let candidates = vec![chars("мачта"), chars("шахта")];
+        assert_eq!(expected_information(&chars("мачта"), &candidates), 1.0);
+        assert_eq!(expected_information(&chars("кубик"), &[chars("мачта")]), 0.0);
+    }
+}
@@ -0,0 +1,180 @@
+//! Backends that answer a constraint set with the candidate words
+
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use crate::dict::{self, DictEntry, DictFormat};
+use crate::{load_words_from_query, Constraints, WordleQuery, WordleQueryError};
+
+/// A store of words that can be searched with a structured constraint set
+pub trait WordSource {
+    /// Every word satisfying the constraints
+    fn candidates(&self, constraints: &Constraints) -> Result<HashSet<String>, WordleQueryError>;
+
+    /// Every word of the given length, used as the pool of legal guesses
+    fn words_of_length(&self, length: usize) -> Result<HashSet<String>, WordleQueryError> {
+        self.candidates(&Constraints::new(length))
+    }
+
+    /// Part of speech recorded for a word, if the source keeps one
+    fn part_of_speech(&self, _word: &str) -> Result<Option<String>, WordleQueryError> {
+        Ok(None)
+    }
+}
+
+// Struct for a word source backed by the SQLite `words` table
+pub struct SqliteSource {
+    conn: Connection,
+}
+
+impl SqliteSource {
+    pub fn new(conn: Connection) -> Self {
+        SqliteSource { conn }
+    }
+
+    /// Open the database file at `path`
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, WordleQueryError> {
+        Ok(SqliteSource::new(Connection::open(path)?))
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+}
+
+impl WordSource for SqliteSource {
+    fn candidates(&self, constraints: &Constraints) -> Result<HashSet<String>, WordleQueryError> {
+        let (query, params) = WordleQuery::from_constraints(constraints.clone()).build_query();
+        Ok(load_words_from_query(&query, &params, &self.conn)?)
+    }
+
+    /// Read the `pos` column written by `dict import`; older databases without it have no parts of speech
+    fn part_of_speech(&self, word: &str) -> Result<Option<String>, WordleQueryError> {
+        let has_pos: bool = self.conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('words') WHERE name = 'pos'",
+            [],
+            |row| row.get(0),
+        )?;
+        if !has_pos {
+            return Ok(None);
+        }
+        let mut stmt = self.conn.prepare_cached("SELECT pos FROM words WHERE word = ?1")?;
+        let mut rows = stmt.query([word])?;
+        Ok(match rows.next()? {
+            Some(row) => row.get(0)?,
+            None => None,
+        })
+    }
+}
+
+// Struct for a word source held in memory and filtered with `Constraints::matches`
+#[derive(Debug, Clone, Default)]
+pub struct MemorySource {
+    words: Vec<String>,
+    parts_of_speech: HashMap<String, String>,
+}
+
+impl MemorySource {
+    /// Keep the words as given; they are expected to be lowercase
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        MemorySource { words: words.into_iter().map(Into::into).collect(), parts_of_speech: HashMap::new() }
+    }
+
+    /// Load the word list compiled into the binary
+    #[cfg(feature = "embedded-words")]
+    pub fn embedded() -> Result<Self, WordleQueryError> {
+        let entries = dict::read_word_list(dict::EMBEDDED_WORDS.as_bytes(), DictFormat::Plain)?;
+        Ok(MemorySource::from_entries(entries))
+    }
+
+    /// Normalize the entries of a word list, dropping non-Russian and repeated words.
+    /// A repeated word keeps its first part of speech, as in `dict::import_entries`.
+    pub fn from_entries(entries: Vec<DictEntry>) -> Self {
+        let mut words = Vec::new();
+        let mut seen = HashSet::new();
+        let mut parts_of_speech = HashMap::new();
+        for entry in entries {
+            let Some(word) = dict::normalize_word(&entry.word) else { continue };
+            if let Some(pos) = entry.pos {
+                parts_of_speech.entry(word.clone()).or_insert(pos);
+            }
+            if seen.insert(word.clone()) {
+                words.push(word);
+            }
+        }
+        MemorySource { words, parts_of_speech }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+}
+
+impl WordSource for MemorySource {
+    fn candidates(&self, constraints: &Constraints) -> Result<HashSet<String>, WordleQueryError> {
+        Ok(self.words.iter().filter(|word| constraints.matches(word)).cloned().collect())
+    }
+
+    fn part_of_speech(&self, word: &str) -> Result<Option<String>, WordleQueryError> {
+        Ok(self.parts_of_speech.get(word).cloned())
+    }
+}
+
+// Struct for a word source read from a word list file, without building a database
+#[derive(Debug, Clone)]
+pub struct FileSource {
+    path: PathBuf,
+    words: MemorySource,
+}
+
+impl FileSource {
+    /// Read the word list once, guessing its format from the extension
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, WordleQueryError> {
+        let path = path.as_ref();
+        FileSource::open_with_format(path, DictFormat::from_path(path))
+    }
+
+    pub fn open_with_format(path: impl AsRef<Path>, format: DictFormat) -> Result<Self, WordleQueryError> {
+        let path = path.as_ref();
+        let reader = BufReader::new(fs::File::open(path)?);
+        let words = MemorySource::from_entries(dict::read_word_list(reader, format)?);
+        Ok(FileSource { path: path.to_path_buf(), words })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl WordSource for FileSource {
+    fn candidates(&self, constraints: &Constraints) -> Result<HashSet<String>, WordleQueryError> {
+        self.words.candidates(constraints)
+    }
+
+    fn part_of_speech(&self, word: &str) -> Result<Option<String>, WordleQueryError> {
+        self.words.part_of_speech(word)
+    }
+}
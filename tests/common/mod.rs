@@ -0,0 +1,38 @@
+//! Fixtures shared by the integration tests
+
+// Each test crate uses only some of the fixtures
+#![allow(dead_code)]
+
+use rust_russian_wordle::{parse_feedback, Constraints, WordleQuery, WordleQueryError};
+
+/// A small five-letter dictionary for solver, multi-board and analysis tests
+pub const WORDS: &[&str] = &["шахта", "мачта", "вахта", "парта", "карта", "жираф", "баран", "слово", "сосна", "голос"];
+
+/// Owned copies of word literals
+pub fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+/// Constraints covering greens, yellows, rejects, letter counts and repeated letters,
+/// for checking that different word sources filter the same way
+pub fn sample_constraints() -> Result<Vec<Constraints>, WordleQueryError> {
+    let mut grey_duplicate = Constraints::new(5);
+    grey_duplicate.add_guess("сосна", &parse_feedback("GYBBB")?)?;
+    let mut yellow_and_grey = Constraints::new(5);
+    yellow_and_grey.add_guess("карта", &parse_feedback("BYGBB")?)?;
+    let mut repeated_yellow = Constraints::new(5);
+    repeated_yellow.add_guess("спуск", &parse_feedback("GBBYB")?)?;
+
+    Ok(vec![
+        Constraints::new(5),
+        WordleQuery::new("****А", "р,п")?.constraints,
+        WordleQuery::new("*О***/о=2", "")?.constraints,
+        WordleQuery::new("*****/о+2", "")?.constraints,
+        WordleQuery::new("*****/о=0/а+1", "")?.constraints,
+        WordleQuery::new("С****", "с")?.constraints,
+        WordleQuery::new("С*_с**", "")?.constraints,
+        grey_duplicate,
+        yellow_and_grey,
+        repeated_yellow,
+    ])
+}
@@ -0,0 +1,74 @@
+use rust_russian_wordle::absurdle::Absurdle;
+use rust_russian_wordle::index::WordIndex;
+use rust_russian_wordle::solver::partition_sizes;
+use rust_russian_wordle::{feedback, Colour, WordleQueryError};
+
+type TestResult = Result<(), WordleQueryError>;
+
+const WORDS: &[&str] = &[
+    "шахта", "мачта", "вахта", "парта", "карта", "жираф", "баран", "слово", "сосна", "голос", "молот", "кубик",
+];
+
+fn chars(word: &str) -> Vec<char> {
+    word.chars().collect()
+}
+
+#[test]
+fn test_keeps_the_largest_group() -> TestResult {
+    let mut game = Absurdle::new(WordIndex::new(WORDS, 5));
+    let candidates: Vec<Vec<char>> = WORDS.iter().map(|w| chars(w)).collect();
+    let largest = partition_sizes(&chars("карта"), &candidates).into_values().max().unwrap_or(0);
+
+    game.guess("карта")?;
+    assert_eq!(game.candidates().len(), largest);
+    Ok(())
+}
+
+#[test]
+fn test_candidates_match_every_row() -> TestResult {
+    let mut game = Absurdle::new(WordIndex::new(WORDS, 5));
+    for guess in ["слово", "карта"] {
+        game.guess(guess)?;
+    }
+    assert!(!game.candidates().is_empty());
+    for candidate in game.candidates() {
+        for (guess, colours) in game.rows() {
+            assert_eq!(&feedback(guess, candidate)?, colours, "{} does not fit {}", candidate, guess);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_avoids_greens_on_a_tie() -> TestResult {
+    // Every word differs from the guess, so each answer is its own group of one
+    let mut game = Absurdle::new(WordIndex::new(["шахта", "кубик"], 5));
+    let colours = game.guess("шахта")?;
+    assert!(colours.iter().all(|&c| c == Colour::Grey));
+    assert_eq!(game.candidates(), ["кубик"]);
+    Ok(())
+}
+
+#[test]
+fn test_game_can_be_won() -> TestResult {
+    let mut game = Absurdle::new(WordIndex::new(WORDS, 5));
+    for _ in 0..WORDS.len() {
+        let next = game.candidates()[0].clone();
+        game.guess(&next)?;
+        if game.is_solved() {
+            break;
+        }
+    }
+    assert!(game.is_solved());
+    assert_eq!(game.candidates().len(), 1);
+    assert!(game.guess("слово").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_invalid_guesses() {
+    let mut game = Absurdle::new(WordIndex::new(WORDS, 5));
+    assert!(game.guess("кот").is_err());
+    assert!(game.guess("абвгд").is_err());
+    assert!(game.rows().is_empty());
+}
@@ -0,0 +1,75 @@
+use rust_russian_wordle::analysis::analyse_game;
+use rust_russian_wordle::index::WordIndex;
+use rust_russian_wordle::{parse_feedback, WordleQueryError};
+
+mod common;
+use common::{words, WORDS};
+
+type TestResult = Result<(), WordleQueryError>;
+
+#[test]
+fn test_analysis_counts_candidates() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let analysis = analyse_game(&index, &words(&["слово", "карта", "шахта"]), "шахта")?;
+
+    assert!(analysis.solved());
+    assert_eq!(analysis.turns.len(), 3);
+
+    let first = &analysis.turns[0];
+    assert_eq!(first.candidates_before, WORDS.len());
+    assert_eq!(first.colours, parse_feedback("BBBBB")?);
+    // No "с", "л", "о" or "в" leaves шахта, мачта, парта, карта, жираф and баран
+    assert_eq!(first.remaining, 6);
+    assert_eq!(analysis.turns[1].candidates_before, 6);
+    assert_eq!(analysis.turns[1].remaining, 2);
+    Ok(())
+}
+
+#[test]
+fn test_best_guess_is_at_least_as_good() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let analysis = analyse_game(&index, &words(&["голос", "сосна", "вахта", "шахта"]), "шахта")?;
+
+    for turn in &analysis.turns {
+        assert!(turn.best_bits >= turn.bits);
+        assert!((0.0..=100.0).contains(&turn.skill));
+    }
+    assert!(analysis.skill() <= 100.0);
+    Ok(())
+}
+
+#[test]
+fn test_optimal_play_scores_full_skill() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let first = analyse_game(&index, &words(&["шахта"]), "шахта")?;
+    let best = first.turns[0].best_guess.clone();
+
+    let analysis = analyse_game(&index, &words(&[&best]), "шахта")?;
+    assert_eq!(analysis.turns[0].best_guess, best);
+    assert!((analysis.turns[0].skill - 100.0).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn test_guesses_after_the_answer_are_ignored() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let analysis = analyse_game(&index, &words(&["шахта", "слово"]), "шахта")?;
+    assert_eq!(analysis.turns.len(), 1);
+    assert_eq!(analysis.turns[0].remaining, 1);
+    Ok(())
+}
+
+#[test]
+fn test_unsolved_game() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let analysis = analyse_game(&index, &words(&["слово"]), "шахта")?;
+    assert!(!analysis.solved());
+    Ok(())
+}
+
+#[test]
+fn test_analysis_length_mismatch() {
+    let index = WordIndex::new(WORDS, 5);
+    assert!(analyse_game(&index, &words(&["кот"]), "шахта").is_err());
+    assert!(analyse_game(&index, &words(&["слово"]), "кот").is_err());
+}
@@ -0,0 +1,68 @@
+use rust_russian_wordle::bench::{BenchReport, GameResult, Simulator};
+use rust_russian_wordle::index::WordIndex;
+use rust_russian_wordle::scoring::FrequencyProduct;
+use rust_russian_wordle::{Strategy, WordleQueryError};
+
+type TestResult = Result<(), WordleQueryError>;
+
+const WORDS: &[&str] = &["шахта", "мачта", "вахта", "парта", "жираф", "баран", "слово", "сосна", "голос", "молот"];
+
+fn game(answer: &str, guesses: &[&str], solved: bool) -> GameResult {
+    GameResult { answer: answer.to_string(), guesses: guesses.iter().map(|g| g.to_string()).collect(), solved }
+}
+
+#[test]
+fn test_every_strategy_solves_every_word() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let scorer = FrequencyProduct::default();
+
+    for strategy in [Strategy::Frequency, Strategy::Entropy, Strategy::Minimax, Strategy::ExpectedRemaining] {
+        let report = Simulator::new(&index, strategy, &scorer).run(WORDS.iter().copied(), 6)?;
+        assert_eq!(report.results.len(), WORDS.len());
+        for result in &report.results {
+            assert!(result.solved, "{:?} failed on {}", strategy, result.answer);
+            assert_eq!(result.guesses.last(), Some(&result.answer));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_games_share_the_opening_guess() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let scorer = FrequencyProduct::default();
+    let report = Simulator::new(&index, Strategy::Entropy, &scorer).run(WORDS.iter().copied(), 6)?;
+
+    let opening = &report.results[0].guesses[0];
+    assert!(report.results.iter().all(|result| &result.guesses[0] == opening));
+    Ok(())
+}
+
+#[test]
+fn test_answer_missing_from_index_is_unsolved() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let scorer = FrequencyProduct::default();
+    let result = Simulator::new(&index, Strategy::Frequency, &scorer).play("кубик")?;
+    assert!(!result.solved);
+    Ok(())
+}
+
+#[test]
+fn test_report_summary() {
+    let report = BenchReport {
+        results: vec![
+            game("шахта", &["слово", "шахта"], true),
+            game("мачта", &["слово", "парта", "вахта", "мачта"], true),
+            game("вахта", &["слово", "парта", "вахта"], true),
+            game("жираф", &["слово"], false),
+        ],
+        max_guesses: 3,
+    };
+
+    assert_eq!(report.distribution().into_iter().collect::<Vec<_>>(), vec![(2, 1), (3, 1), (4, 1)]);
+    assert_eq!(report.failures(), 2);
+    assert_eq!(report.average_guesses(), 3.0);
+
+    let hardest: Vec<&str> = report.hardest(2).iter().map(|r| r.answer.as_str()).collect();
+    assert_eq!(hardest, vec!["жираф", "мачта"]);
+}
@@ -0,0 +1,52 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rust_russian_wordle::WordleQueryError;
+
+type TestResult = Result<(), WordleQueryError>;
+
+/// Run the binary against a small word list, feeding `stdin` to it, and return its standard output
+fn run(args: &[&str], stdin: &str) -> Result<String, WordleQueryError> {
+    // Tests run in parallel, so each call gets its own word list file
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    let run = RUNS.fetch_add(1, Ordering::Relaxed);
+    let path = std::env::temp_dir().join(format!("ruwordle_cli_{}_{}.txt", std::process::id(), run));
+    fs::write(&path, "слово\nкарта\nшахта\nмачта\nвахта\n")?;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rust_russian_wordle"))
+        .arg("--wordlist")
+        .arg(&path)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(stdin.as_bytes())?;
+    let output = child.wait_with_output()?;
+    fs::remove_file(&path)?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[test]
+fn test_guesses_continue_from_a_share_grid() -> TestResult {
+    let grid = "Wordle 2/6\n\n⬜⬜⬜⬜⬜\n⬜🟩⬜🟩🟩\n";
+    let args = ["--share", "-", "--share-words", "слово карта", "-g", "шахта:GGGGG"];
+    let output = run(&args, grid)?;
+
+    // The share grid rows come first, so the final guess solves the puzzle on the third row
+    assert!(output.contains("Solved in 3 guesses!"), "{}", output);
+    assert!(output.contains("⬜⬜⬜⬜⬜\n⬜🟩⬜🟩🟩\n🟩🟩🟩🟩🟩"), "{}", output);
+
+    let output = run(&["--share", "-", "--share-words", "слово карта"], grid)?;
+    assert!(output.contains("Remaining candidates: 2"), "{}", output);
+    Ok(())
+}
+
+#[test]
+fn test_solving_with_one_guess_is_singular() -> TestResult {
+    let output = run(&["-g", "шахта:GGGGG", "--max-attempts", "1"], "")?;
+    assert!(output.contains("Solved in 1 guess!"), "{}", output);
+    assert!(output.contains("Wordle 1/1"), "{}", output);
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use rust_russian_wordle::daily::{DailyPuzzle, PuzzleDate, DAILY_EPOCH};
+use rust_russian_wordle::WordleQueryError;
+use std::collections::HashSet;
+
+type TestResult = Result<(), WordleQueryError>;
+
+fn words(count: usize) -> Vec<String> {
+    let letters: Vec<char> = ('а'..='я').collect();
+    (0..count)
+        .map(|i| {
+            let a = letters[i % 32];
+            let b = letters[(i / 32) % 32];
+            format!("{}{}ааа", a, b)
+        })
+        .collect()
+}
+
+#[test]
+fn test_date_round_trip() -> TestResult {
+    for text in ["1970-01-01", "2000-02-29", "2024-01-01", "2024-12-31", "2026-10-16", "2100-03-01"] {
+        let date: PuzzleDate = text.parse()?;
+        assert_eq!(date.to_string(), text);
+        assert_eq!(PuzzleDate::from_days(date.days()), date);
+    }
+    assert_eq!("1970-01-01".parse::<PuzzleDate>()?.days(), 0);
+    assert_eq!("2024-03-01".parse::<PuzzleDate>()?.days() - "2024-02-28".parse::<PuzzleDate>()?.days(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_dates() {
+    for text in ["2024-02-30", "2023-02-29", "2024-13-01", "2024-00-10", "2024-01-00", "yesterday", "2024-01"] {
+        assert!(text.parse::<PuzzleDate>().is_err(), "{} should not parse", text);
+    }
+}
+
+#[test]
+fn test_puzzle_numbers() -> TestResult {
+    assert_eq!(DAILY_EPOCH.puzzle_number()?, 1);
+    assert_eq!("2024-01-31".parse::<PuzzleDate>()?.puzzle_number()?, 31);
+    assert!("2023-12-31".parse::<PuzzleDate>()?.puzzle_number().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_answer_is_deterministic() -> TestResult {
+    let date: PuzzleDate = "2024-06-01".parse()?;
+    let puzzle = DailyPuzzle::new(words(200), "", 30);
+    // The order the words arrive in does not matter
+    let reversed = DailyPuzzle::new(words(200).into_iter().rev(), "", 30);
+
+    assert_eq!(puzzle.answer(date)?, puzzle.answer(date)?);
+    assert_eq!(puzzle.answer(date)?, reversed.answer(date)?);
+    Ok(())
+}
+
+#[test]
+fn test_salt_changes_the_sequence() -> TestResult {
+    let plain = DailyPuzzle::new(words(500), "", 30);
+    let salted = DailyPuzzle::new(words(500), "team", 30);
+
+    let start = DAILY_EPOCH.days();
+    let differing = (0..20)
+        .map(|offset| PuzzleDate::from_days(start + offset))
+        .filter(|&date| plain.answer(date).ok() != salted.answer(date).ok())
+        .count();
+    assert!(differing > 10);
+    Ok(())
+}
+
+#[test]
+fn test_no_repeats_within_window() -> TestResult {
+    let window = 40;
+    let puzzle = DailyPuzzle::new(words(60), "", window);
+
+    let answers: Vec<String> = (0..150)
+        .map(|offset| puzzle.answer(PuzzleDate::from_days(DAILY_EPOCH.days() + offset)).map(str::to_string))
+        .collect::<Result<_, _>>()?;
+    for run in answers.windows(window) {
+        let distinct: HashSet<&String> = run.iter().collect();
+        assert_eq!(distinct.len(), window);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_window_larger_than_dictionary() -> TestResult {
+    // With three words the window shrinks to two days, so the answers cycle through all of them
+    let puzzle = DailyPuzzle::new(words(3), "", 365);
+    let answers: Vec<&str> = (0..6)
+        .map(|offset| puzzle.answer(PuzzleDate::from_days(DAILY_EPOCH.days() + offset)))
+        .collect::<Result<_, _>>()?;
+    for run in answers.windows(3) {
+        assert_eq!(run.iter().collect::<HashSet<_>>().len(), 3);
+    }
+    Ok(())
+}
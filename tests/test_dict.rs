@@ -0,0 +1,172 @@
+use rusqlite::Connection;
+use rust_russian_wordle::dict::{import_entries, normalize_word, read_word_list, DictEntry, DictFormat};
+use rust_russian_wordle::{load_words_from_query, WordleQuery, WordleQueryError};
+use std::path::Path;
+
+type TestResult = Result<(), WordleQueryError>;
+
+#[test]
+fn test_normalize_word() {
+    assert_eq!(normalize_word(" Ёлка "), Some("елка".to_string()));
+    assert_eq!(normalize_word("кто-то"), None);
+    assert_eq!(normalize_word("word"), None);
+    assert_eq!(normalize_word(""), None);
+}
+
+#[test]
+fn test_format_from_path() {
+    assert_eq!(DictFormat::from_path(Path::new("words.CSV")), DictFormat::Csv);
+    assert_eq!(DictFormat::from_path(Path::new("words.tsv")), DictFormat::Tsv);
+    assert_eq!(DictFormat::from_path(Path::new("words.txt")), DictFormat::Plain);
+}
+
+#[test]
+fn test_read_plain_word_list() -> TestResult {
+    let input = "# comment\nслово\n\n  мачта  \n";
+    let entries = read_word_list(input.as_bytes(), DictFormat::Plain)?;
+    let words: Vec<&str> = entries.iter().map(|e| e.word.as_str()).collect();
+    assert_eq!(words, vec!["слово", "мачта"]);
+    Ok(())
+}
+
+#[test]
+fn test_read_tsv_with_header() -> TestResult {
+    let input = "pos\tword\tfreq\nNOUN\tслово\t12.5\nVERB\tжить\t\n";
+    let entries = read_word_list(input.as_bytes(), DictFormat::Tsv)?;
+    assert_eq!(entries[0], DictEntry { word: "слово".into(), frequency: Some(12.5), pos: Some("NOUN".into()) });
+    assert_eq!(entries[1].frequency, None);
+    Ok(())
+}
+
+#[test]
+fn test_read_csv_without_header() -> TestResult {
+    let input = "слово,12\nмачта\n";
+    let entries = read_word_list(input.as_bytes(), DictFormat::Csv)?;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].frequency, Some(12.0));
+    assert_eq!(entries[1].pos, None);
+    Ok(())
+}
+
+#[test]
+fn test_import_normalizes_and_deduplicates() -> TestResult {
+    let mut conn = Connection::open_in_memory()?;
+    let entries = read_word_list("word,frequency,pos\nСлово,10,NOUN\nслово,12,\nсЁмга,2,\nкто-то,1,PRON\n".as_bytes(), DictFormat::Csv)?;
+    let stats = import_entries(&mut conn, entries)?;
+
+    assert_eq!(stats.read, 4);
+    assert_eq!(stats.skipped, 1);
+    assert_eq!(stats.duplicates, 1);
+    assert_eq!(stats.imported, 2);
+
+    let (frequency, pos): (f64, String) = conn.query_row(
+        "SELECT frequency, pos FROM words WHERE word = 'слово'",
+        [],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    assert_eq!(frequency, 12.0);
+    assert_eq!(pos, "NOUN");
+
+    // The imported table works with the query layer as is
+    let (query, params) = WordleQuery::new("*****", "")?.build_query();
+    let mut words: Vec<String> = load_words_from_query(&query, &params, &conn)?.into_iter().collect();
+    words.sort();
+    assert_eq!(words, vec!["семга", "слово"]);
+    Ok(())
+}
+
+#[test]
+fn test_import_twice_merges_with_existing_rows() -> TestResult {
+    let mut conn = Connection::open_in_memory()?;
+    import_entries(&mut conn, read_word_list("слово,5\n".as_bytes(), DictFormat::Csv)?)?;
+    import_entries(&mut conn, read_word_list("слово,9,NOUN\n".as_bytes(), DictFormat::Csv)?)?;
+
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM words", [], |row| row.get(0))?;
+    let frequency: f64 = conn.query_row("SELECT frequency FROM words", [], |row| row.get(0))?;
+    assert_eq!(count, 1);
+    assert_eq!(frequency, 9.0);
+    Ok(())
+}
+
+#[test]
+fn test_import_into_a_legacy_table() -> TestResult {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(
+        "CREATE TABLE words (word TEXT NOT NULL);
+         INSERT INTO words (word) VALUES ('шахта'), ('слово');",
+    )?;
+    import_entries(&mut conn, read_word_list("слово,9,NOUN\nжираф,3\n".as_bytes(), DictFormat::Csv)?)?;
+
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM words", [], |row| row.get(0))?;
+    let (frequency, pos): (f64, String) =
+        conn.query_row("SELECT frequency, pos FROM words WHERE word = 'слово'", [], |row| Ok((row.get(0)?, row.get(1)?)))?;
+    assert_eq!(count, 3);
+    assert_eq!((frequency, pos.as_str()), (9.0, "NOUN"));
+    Ok(())
+}
+
+#[test]
+fn test_import_upgrades_a_legacy_table_in_place() -> TestResult {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(
+        "CREATE TABLE words (id INTEGER PRIMARY KEY, word TEXT, lemma TEXT, freq_ipm REAL);
+         INSERT INTO words (id, word, lemma, freq_ipm) VALUES (1, 'Мирно', 'мирный', 12.5), (2, 'шахта', 'шахта', 30.0);
+         CREATE VIEW frequent AS SELECT word, lemma FROM words WHERE freq_ipm > 20;",
+    )?;
+    import_entries(&mut conn, read_word_list("шахта,9,NOUN\n".as_bytes(), DictFormat::Csv)?)?;
+
+    let (id, lemma, freq_ipm): (i64, String, f64) =
+        conn.query_row("SELECT id, lemma, freq_ipm FROM words WHERE word = 'мирно'", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })?;
+    assert_eq!((id, lemma.as_str(), freq_ipm), (1, "мирный", 12.5));
+    let frequent: String = conn.query_row("SELECT word FROM frequent", [], |row| row.get(0))?;
+    assert_eq!(frequent, "шахта");
+    let pos: String = conn.query_row("SELECT pos FROM words WHERE word = 'шахта'", [], |row| row.get(0))?;
+    assert_eq!(pos, "NOUN");
+
+    let (query, params) = WordleQuery::new("М****", "")?.build_query();
+    assert!(load_words_from_query(&query, &params, &conn)?.contains("мирно"));
+    Ok(())
+}
+
+#[test]
+fn test_import_stops_on_a_legacy_table_with_repeated_words() -> TestResult {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE words (word TEXT); INSERT INTO words (word) VALUES ('Мирно'), ('мирно');")?;
+    let result = import_entries(&mut conn, read_word_list("слово\n".as_bytes(), DictFormat::Plain)?);
+    assert!(matches!(result, Err(WordleQueryError::ImportError(_))));
+
+    // Nothing is changed when the table cannot be upgraded
+    let columns: i64 = conn.query_row("SELECT COUNT(*) FROM pragma_table_info('words')", [], |row| row.get(0))?;
+    let count: i64 = conn.query_row("SELECT COUNT(*) FROM words", [], |row| row.get(0))?;
+    assert_eq!((columns, count), (1, 2));
+    Ok(())
+}
+
+#[test]
+fn test_import_rejects_a_table_without_words() -> TestResult {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute("CREATE TABLE words (lemma TEXT)", [])?;
+    let result = import_entries(&mut conn, read_word_list("слово\n".as_bytes(), DictFormat::Plain)?);
+    assert!(matches!(result, Err(WordleQueryError::ImportError(_))));
+    Ok(())
+}
+
+#[cfg(feature = "embedded-words")]
+#[test]
+fn test_embedded_word_list_is_queryable() -> TestResult {
+    use rust_russian_wordle::dict::EMBEDDED_WORDS;
+
+    let mut conn = Connection::open_in_memory()?;
+    import_entries(&mut conn, read_word_list(EMBEDDED_WORDS.as_bytes(), DictFormat::Plain)?)?;
+    let (query, params) = WordleQuery::new("*****", "")?.build_query();
+    let words = load_words_from_query(&query, &params, &conn)?;
+
+    let count: usize = conn.query_row("SELECT COUNT(*) FROM words", [], |row| row.get(0))?;
+    assert!(count > 1000);
+    // Every embedded word already satisfies the query layer's own filters
+    assert_eq!(words.len(), count);
+    assert!(words.contains("слово"));
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use rust_russian_wordle::source::{MemorySource, WordSource};
+use rust_russian_wordle::{feedback, parse_feedback, Colour, Constraints, WordleQueryError};
+
+type TestResult = Result<(), WordleQueryError>;
+
+const WORDS: &[&str] = &[
+    "слово", "сосна", "стоик", "сокол", "голос", "молот", "шахта", "мачта", "вахта", "парта", "жираф", "баран",
+    "спуск", "сазан", "норка", "кубик", "карта", "ооооо",
+];
+
+#[test]
+fn test_feedback_exact_match() -> TestResult {
+    assert_eq!(feedback("слово", "слово")?, vec![Colour::Green; 5]);
+    Ok(())
+}
+
+#[test]
+fn test_feedback_no_common_letters() -> TestResult {
+    assert_eq!(feedback("кубик", "слово")?, vec![Colour::Grey; 5]);
+    Ok(())
+}
+
+#[test]
+fn test_feedback_duplicate_in_guess_single_in_answer() -> TestResult {
+    // Only one "с" in "слово": the first "с" of "сосна" is green, the second grey
+    assert_eq!(feedback("сосна", "слово")?, parse_feedback("GYBBB")?);
+    // The yellow goes to the leftmost unmatched copy
+    assert_eq!(feedback("карта", "жираф")?, parse_feedback("BYGBB")?);
+    Ok(())
+}
+
+#[test]
+fn test_feedback_green_takes_priority_over_earlier_yellow() -> TestResult {
+    // The only "о" of "норка" is used up by the green, so no other "о" turns yellow
+    assert_eq!(feedback("ооооо", "норка")?, parse_feedback("BGBBB")?);
+    assert_eq!(feedback("ооооо", "слово")?, parse_feedback("BBGBG")?);
+    Ok(())
+}
+
+#[test]
+fn test_feedback_duplicates_in_both_words() -> TestResult {
+    // "голос" has two "о"; "молот" has two "о" in the same places
+    assert_eq!(feedback("голос", "молот")?, parse_feedback("BGGGB")?);
+    // "спуск" has two "с"; "сосна" has two "с", one in the same place
+    assert_eq!(feedback("спуск", "сосна")?, parse_feedback("GBBYB")?);
+    Ok(())
+}
+
+#[test]
+fn test_feedback_normalizes_case_and_yo() -> TestResult {
+    assert_eq!(feedback("ЁЖИКИ", "ежики")?, vec![Colour::Green; 5]);
+    Ok(())
+}
+
+#[test]
+fn test_feedback_length_mismatch() {
+    assert!(feedback("слов", "слово").is_err());
+}
+
+#[test]
+fn test_constraints_from_feedback() -> TestResult {
+    let constraints = Constraints::from_feedback("сосна", &feedback("сосна", "слово")?)?;
+    assert_eq!(constraints.greens[0], Some('с'));
+    assert_eq!(constraints.max_counts.get(&'с'), Some(&1));
+    assert!(constraints.rejects.contains(&'н'));
+    Ok(())
+}
+
+#[test]
+fn test_feedback_constraints_keep_the_answer() -> TestResult {
+    // Whatever the guess, the answer must survive the constraints built from its own feedback,
+    // and every word that survives must give the same feedback
+    let source = MemorySource::new(WORDS.iter().copied());
+    for guess in WORDS {
+        for answer in WORDS {
+            let colours = feedback(guess, answer)?;
+            let candidates = source.candidates(&Constraints::from_feedback(guess, &colours)?)?;
+            assert!(candidates.contains(*answer), "{} lost after guessing {}", answer, guess);
+            for candidate in &candidates {
+                assert_eq!(feedback(guess, candidate)?, colours, "{} kept after guessing {} for {}", candidate, guess, answer);
+            }
+        }
+    }
+    Ok(())
+}
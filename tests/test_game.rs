@@ -0,0 +1,86 @@
+use rust_russian_wordle::game::{random_word, Game, GameStatus};
+use rust_russian_wordle::{parse_feedback, Colour, WordleQueryError};
+
+type TestResult = Result<(), WordleQueryError>;
+
+const WORDS: &[&str] = &["слово", "сосна", "шахта", "мачта", "вахта", "парта", "карта", "атлас", "жираф", "кубик"];
+
+#[test]
+fn test_winning_game() -> TestResult {
+    let mut game = Game::new("шахта", WORDS.iter().copied(), 6, false);
+    assert_eq!(game.guess("мачта")?, parse_feedback("BGBGG")?);
+    assert_eq!(game.status(), GameStatus::InProgress);
+    assert_eq!(game.guess("ШАХТА")?, vec![Colour::Green; 5]);
+    assert_eq!(game.status(), GameStatus::Won);
+    assert!(game.guess("слово").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_losing_game() -> TestResult {
+    let mut game = Game::new("шахта", WORDS.iter().copied(), 2, false);
+    game.guess("слово")?;
+    game.guess("кубик")?;
+    assert_eq!(game.status(), GameStatus::Lost);
+    assert_eq!(game.attempts_left(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_guesses_do_not_use_attempts() {
+    let mut game = Game::new("шахта", WORDS.iter().copied(), 6, false);
+    assert!(game.guess("кот").is_err());
+    assert!(game.guess("абвгд").is_err());
+    assert_eq!(game.attempts_left(), 6);
+}
+
+#[test]
+fn test_hard_mode_keeps_greens_and_revealed_letters() -> TestResult {
+    let mut game = Game::new("шахта", WORDS.iter().copied(), 6, true);
+    game.guess("карта")?;
+
+    // "а" at position 2 and "та" at the end are green
+    assert!(game.guess("слово").is_err());
+    assert!(game.guess("кубик").is_err());
+    game.guess("мачта")?;
+    assert_eq!(game.rows().len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_hard_mode_requires_yellow_letters() -> TestResult {
+    let mut game = Game::new("жираф", WORDS.iter().copied(), 6, true);
+    game.guess("карта")?;
+
+    // The yellow "а" must be reused; "р" is green at position 3
+    assert!(game.guess("кубик").is_err());
+    game.guess("парта")?;
+    Ok(())
+}
+
+#[test]
+fn test_keyboard_keeps_the_best_colour() -> TestResult {
+    let mut game = Game::new("шахта", WORDS.iter().copied(), 6, false);
+    assert_eq!(game.letter_state('а'), None);
+
+    game.guess("сосна")?;
+    assert_eq!(game.letter_state('а'), Some(Colour::Green));
+    assert_eq!(game.letter_state('с'), Some(Colour::Grey));
+
+    game.guess("мачта")?;
+    assert_eq!(game.letter_state('т'), Some(Colour::Green));
+
+    // "атлас" shows both "а"s and the "т" as yellow, but their keys stay green
+    assert_eq!(game.guess("атлас")?, parse_feedback("YYBYB")?);
+    assert_eq!(game.letter_state('а'), Some(Colour::Green));
+    assert_eq!(game.letter_state('т'), Some(Colour::Green));
+    assert_eq!(game.letter_state('л'), Some(Colour::Grey));
+    Ok(())
+}
+
+#[test]
+fn test_random_word_comes_from_the_list() {
+    let words: Vec<String> = WORDS.iter().map(|w| w.to_string()).collect();
+    assert!(words.contains(random_word(&words).unwrap()));
+    assert!(random_word(&[]).is_none());
+}
@@ -0,0 +1,184 @@
+use rusqlite::{Connection, Result};
+use rust_russian_wordle::{
+    load_words_from_query, parse_feedback, parse_guess, Colour, Constraints, WordleQuery, WordleQueryError,
+};
+
+type TestResult = Result<(), WordleQueryError>;
+
+fn words_matching(constraints: Constraints, test_data: &[&str]) -> Result<Vec<String>, WordleQueryError> {
+    let conn = Connection::open_in_memory()?;
+    conn.execute("CREATE TABLE words (word TEXT NOT NULL)", [])?;
+    for word in test_data {
+        conn.execute("INSERT INTO words (word) VALUES (?1)", [word])?;
+    }
+
+    let (query, params) = WordleQuery::from_constraints(constraints).build_query();
+    let mut results: Vec<String> = load_words_from_query(&query, &params, &conn)?.into_iter().collect();
+    results.sort();
+    Ok(results)
+}
+
+#[test]
+fn test_parse_feedback_latin_and_cyrillic() -> TestResult {
+    let expected = vec![Colour::Green, Colour::Yellow, Colour::Grey, Colour::Grey, Colour::Green];
+    assert_eq!(parse_feedback("GYBBG")?, expected);
+    assert_eq!(parse_feedback("gybbg")?, expected);
+    assert_eq!(parse_feedback("зжссз")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_parse_feedback_rejects_unknown_characters() {
+    assert!(parse_feedback("GYXBG").is_err());
+}
+
+#[test]
+fn test_parse_guess_normalizes_word() -> TestResult {
+    let (word, feedback) = parse_guess("ЛЁСOК:BBBBB")?;
+    assert_eq!(word, "лесок");
+    assert_eq!(feedback.len(), 5);
+    Ok(())
+}
+
+#[test]
+fn test_parse_guess_requires_separator() {
+    assert!(parse_guess("словоGYBBG").is_err());
+}
+
+#[test]
+fn test_add_guess_builds_constraints() -> TestResult {
+    let mut constraints = Constraints::new(5);
+    constraints.add_guess("слово", &parse_feedback("GYBBB")?)?;
+
+    assert_eq!(constraints.greens[0], Some('с'));
+    assert_eq!(constraints.required, vec!['л']);
+    assert_eq!(constraints.misplaced[1], vec!['л']);
+    assert_eq!(constraints.rejects, vec!['о', 'в']);
+    Ok(())
+}
+
+#[test]
+fn test_add_guess_length_mismatch() -> TestResult {
+    let mut constraints = Constraints::new(5);
+    assert!(constraints.add_guess("слов", &parse_feedback("GYBB")?).is_err());
+    assert!(constraints.add_guess("слово", &parse_feedback("GYBB")?).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_multiple_guesses_accumulate() -> TestResult {
+    let mut constraints = Constraints::new(5);
+    constraints.add_guess("слово", &parse_feedback("BBBBB")?)?;
+    constraints.add_guess("карта", &parse_feedback("BGBGG")?)?;
+
+    let results = words_matching(constraints, &["шахта", "рамка", "парта", "вахта", "мачта"])?;
+    assert_eq!(results, vec!["мачта", "шахта"]);
+    Ok(())
+}
+
+#[test]
+fn test_guess_merges_with_pattern() -> TestResult {
+    let mut constraints = WordleQuery::new("****А", "")?.constraints;
+    constraints.add_guess("склад", &parse_feedback("BBBYB")?)?;
+
+    let results = words_matching(constraints, &["шахта", "мачта", "вилка", "тачка"])?;
+    assert_eq!(results, vec!["мачта", "шахта"]);
+    Ok(())
+}
+
+#[test]
+fn test_grey_duplicate_is_not_a_global_reject() -> TestResult {
+    // Answer "слово": the second "с" of "сосна" is grey, but "с" is still in the word
+    let mut constraints = Constraints::new(5);
+    constraints.add_guess("сосна", &parse_feedback("GYBBB")?)?;
+
+    assert!(!constraints.rejects.contains(&'с'));
+    assert_eq!(constraints.misplaced[2], vec!['с']);
+    assert_eq!(constraints.max_counts.get(&'с'), Some(&1));
+
+    let results = words_matching(constraints, &["слово", "сосок", "стоик", "сокол"])?;
+    assert_eq!(results, vec!["слово", "стоик"]);
+    Ok(())
+}
+
+#[test]
+fn test_yellow_and_grey_duplicate() -> TestResult {
+    // Answer "жираф": the first "а" of "карта" is yellow and the second is grey
+    let mut constraints = Constraints::new(5);
+    constraints.add_guess("карта", &parse_feedback("BYGBB")?)?;
+
+    assert_eq!(constraints.required, vec!['а']);
+    assert_eq!(constraints.misplaced[4], vec!['а']);
+    assert_eq!(constraints.rejects, vec!['к', 'т']);
+
+    let results = words_matching(constraints, &["жираф", "парад", "баран", "марля"])?;
+    assert_eq!(results, vec!["жираф"]);
+    Ok(())
+}
+
+#[test]
+fn test_pattern_grey_duplicate() -> TestResult {
+    // "_с" next to a green "С" means only one "с", and not in the third position
+    let constraints = WordleQuery::new("С*_с**", "")?.constraints;
+
+    assert!(!constraints.rejects.contains(&'с'));
+    let results = words_matching(constraints, &["слово", "сосна", "стоик", "спуск"])?;
+    assert_eq!(results, vec!["слово", "стоик"]);
+    Ok(())
+}
+
+#[test]
+fn test_rejects_flag_does_not_remove_found_letter() -> TestResult {
+    // Listing a green letter in --rejects only rules out further copies
+    let constraints = WordleQuery::new("С****", "с")?.constraints;
+
+    let results = words_matching(constraints, &["слово", "спуск"])?;
+    assert_eq!(results, vec!["слово"]);
+    Ok(())
+}
+
+#[test]
+fn test_repeated_yellow_requires_two_copies() -> TestResult {
+    // Answer "сосна": both "с" of "спуск" are in the word, so at least two are required
+    let mut constraints = Constraints::new(5);
+    constraints.add_guess("спуск", &parse_feedback("GBBYB")?)?;
+
+    assert_eq!(constraints.min_counts.get(&'с'), Some(&2));
+    let results = words_matching(constraints, &["сосна", "слово", "сазан"])?;
+    assert_eq!(results, vec!["сосна"]);
+    Ok(())
+}
+
+#[test]
+fn test_pattern_exact_count() -> TestResult {
+    let constraints = WordleQuery::new("*О***/о=2", "")?.constraints;
+
+    let results = words_matching(constraints, &["голос", "слово", "норка", "молот", "ооооо"])?;
+    assert_eq!(results, vec!["голос", "молот"]);
+    Ok(())
+}
+
+#[test]
+fn test_pattern_at_least_count() -> TestResult {
+    let constraints = WordleQuery::new("*****/о+2", "")?.constraints;
+
+    let results = words_matching(constraints, &["голос", "слово", "норка", "ооооо"])?;
+    assert_eq!(results, vec!["голос", "ооооо", "слово"]);
+    Ok(())
+}
+
+#[test]
+fn test_pattern_zero_count_rejects_letter() -> TestResult {
+    let constraints = WordleQuery::new("*****/о=0/а+1", "")?.constraints;
+
+    let results = words_matching(constraints, &["голос", "шахта", "кубик"])?;
+    assert_eq!(results, vec!["шахта"]);
+    Ok(())
+}
+
+#[test]
+fn test_pattern_invalid_count() {
+    assert!(WordleQuery::new("*****/о2", "").is_err());
+    assert!(WordleQuery::new("*****/о=x", "").is_err());
+    assert!(WordleQuery::new("*****/=2", "").is_err());
+}
@@ -0,0 +1,131 @@
+use rusqlite::Connection;
+use rust_russian_wordle::dict::{self, DictEntry};
+use rust_russian_wordle::hint::{give_hint, likely_letter, parts_of_speech, repeated_letters, Hint, HintKind};
+use rust_russian_wordle::scoring::FrequencyProduct;
+use rust_russian_wordle::source::{MemorySource, SqliteSource, WordSource};
+use rust_russian_wordle::{parse_feedback, Constraints, Strategy, WordleQueryError};
+
+mod common;
+use common::words;
+
+type TestResult = Result<(), WordleQueryError>;
+
+fn entry(word: &str, pos: Option<&str>) -> DictEntry {
+    DictEntry { word: word.to_string(), frequency: None, pos: pos.map(str::to_string) }
+}
+
+#[test]
+fn test_hint_kinds_parse_and_follow_each_other() -> TestResult {
+    assert_eq!("count".parse::<HintKind>()?, HintKind::Count);
+    assert_eq!("Letter".parse::<HintKind>()?, HintKind::Letter);
+    assert_eq!("pos".parse::<HintKind>()?, HintKind::PartOfSpeech);
+    assert_eq!("5".parse::<HintKind>()?, HintKind::Guess);
+    assert!("spoiler".parse::<HintKind>().is_err());
+
+    let mut order = vec![HintKind::Count];
+    while let Some(next) = order.last().and_then(|kind| kind.next()) {
+        order.push(next);
+    }
+    assert_eq!(order, HintKind::ALL);
+    Ok(())
+}
+
+#[test]
+fn test_likely_letter_skips_green_positions() -> TestResult {
+    let candidates = words(&["шахта", "мачта", "вахта", "парта"]);
+    let mut constraints = Constraints::new(5);
+    constraints.add_guess("карта", &parse_feedback("BGBGG")?)?;
+
+    // Positions 2, 4 and 5 are green, so the hint comes from the first or third letter
+    let hint = likely_letter(&constraints, &candidates).unwrap();
+    assert_eq!((hint.position, hint.letter), (2, 'х'));
+    assert_eq!(hint.share, 50.0);
+
+    let mut solved = Constraints::new(5);
+    solved.add_guess("шахта", &parse_feedback("GGGGG")?)?;
+    assert_eq!(likely_letter(&solved, &words(&["шахта"])), None);
+    Ok(())
+}
+
+#[test]
+fn test_repeated_letters() {
+    assert_eq!(repeated_letters(&words(&["шахта", "слово", "голос", "жираф"])), 3);
+    assert_eq!(Hint::Repeats { repeated: 0, total: 2 }.to_string(), "No candidate repeats a letter");
+    assert_eq!(Hint::Repeats { repeated: 2, total: 2 }.to_string(), "Every candidate repeats a letter");
+    assert_eq!(Hint::Repeats { repeated: 1, total: 2 }.to_string(), "1 of 2 candidates repeat a letter");
+}
+
+#[test]
+fn test_parts_of_speech_from_a_word_list() -> TestResult {
+    let source = MemorySource::from_entries(vec![
+        entry("шахта", Some("noun")),
+        entry("Шахта", Some("verb")),
+        entry("читать", Some("VERB")),
+        entry("слово", None),
+    ]);
+    assert_eq!(source.part_of_speech("шахта")?.as_deref(), Some("noun"));
+    assert_eq!(source.part_of_speech("слово")?, None);
+
+    let counts = parts_of_speech(&words(&["шахта", "читать", "слово"]), &source)?;
+    assert_eq!(counts.into_iter().collect::<Vec<_>>(), [("noun".to_string(), 1), ("verb".to_string(), 1)]);
+    Ok(())
+}
+
+#[test]
+fn test_parts_of_speech_from_sqlite() -> TestResult {
+    let mut conn = Connection::open_in_memory()?;
+    conn.execute_batch(dict::SCHEMA)?;
+    dict::import_entries(&mut conn, vec![entry("шахта", Some("noun")), entry("слово", None)])?;
+    let source = SqliteSource::new(conn);
+    assert_eq!(source.part_of_speech("шахта")?.as_deref(), Some("noun"));
+    assert_eq!(source.part_of_speech("слово")?, None);
+    assert_eq!(source.part_of_speech("жираф")?, None);
+
+    // Databases created before parts of speech were recorded have no `pos` column
+    let conn = Connection::open_in_memory()?;
+    conn.execute("CREATE TABLE words (word TEXT NOT NULL)", [])?;
+    conn.execute("INSERT INTO words (word) VALUES ('шахта')", [])?;
+    assert_eq!(SqliteSource::new(conn).part_of_speech("шахта")?, None);
+    Ok(())
+}
+
+#[test]
+fn test_give_hint() -> TestResult {
+    let source = MemorySource::from_entries(vec![entry("шахта", Some("noun")), entry("мачта", Some("noun"))]);
+    let candidates = words(&["шахта", "мачта"]);
+    let constraints = Constraints::new(5);
+    let scorer = FrequencyProduct::default();
+    let hint = |kind| give_hint(kind, &constraints, &candidates, &candidates, Strategy::Entropy, &scorer, &source);
+
+    assert_eq!(hint(HintKind::Count)?.to_string(), "2 candidates remain");
+    assert_eq!(hint(HintKind::PartOfSpeech)?.to_string(), "Every candidate is a noun");
+    let partly_tagged = Hint::PartOfSpeech { counts: [("noun".to_string(), 1)].into(), total: 2 };
+    assert_eq!(partly_tagged.to_string(), "Parts of speech: noun 1");
+    match hint(HintKind::Guess)? {
+        Hint::Guess(Some(word)) => assert!(candidates.contains(&word)),
+        other => panic!("expected a guess, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[cfg(feature = "embedded-words")]
+#[test]
+fn test_embedded_list_has_no_parts_of_speech() -> TestResult {
+    let source = MemorySource::embedded()?;
+    assert_eq!(source.part_of_speech("слово")?, None);
+    let counts = parts_of_speech(&words(&["слово"]), &source)?;
+    assert_eq!(Hint::PartOfSpeech { counts, total: 1 }.to_string(), "The part of speech is unknown");
+    Ok(())
+}
+
+#[test]
+fn test_hints_with_no_candidates() -> TestResult {
+    let source = MemorySource::from_entries(vec![entry("шахта", Some("noun"))]);
+    let constraints = Constraints::new(5);
+    let scorer = FrequencyProduct::default();
+    let hint = |kind| give_hint(kind, &constraints, &[], &words(&["шахта"]), Strategy::Entropy, &scorer, &source);
+
+    assert_eq!(hint(HintKind::Repeats)?.to_string(), "No candidates remain");
+    assert_eq!(hint(HintKind::PartOfSpeech)?.to_string(), "No candidates remain");
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use rust_russian_wordle::index::WordIndex;
+use rust_russian_wordle::source::{MemorySource, WordSource};
+use rust_russian_wordle::{Constraints, WordleQuery, WordleQueryError};
+
+mod common;
+use common::sample_constraints;
+
+type TestResult = Result<(), WordleQueryError>;
+
+const WORDS: &[&str] = &[
+    "слово", "сосна", "стоик", "сокол", "голос", "молот", "шахта", "мачта", "вахта", "парта", "жираф", "баран",
+    "ооооо", "спуск", "сазан", "норка", "кубик",
+];
+
+#[test]
+fn test_index_agrees_with_memory_source() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+    let memory = MemorySource::new(WORDS.iter().copied());
+
+    for constraints in sample_constraints()? {
+        let mut expected: Vec<String> = memory.candidates(&constraints)?.into_iter().collect();
+        expected.sort();
+        assert_eq!(index.filter(&constraints), expected, "constraints:\n{}", constraints);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_index_normalizes_and_deduplicates() {
+    let index = WordIndex::new(["Ёжики", "ежики", "кто-то", "word", "кот", "шахта"], 5);
+    assert_eq!(index.words(), ["ежики", "шахта"]);
+    assert_eq!(index.length(), 5);
+}
+
+#[test]
+fn test_index_ignores_other_lengths() {
+    let index = WordIndex::new(WORDS, 5);
+    assert!(index.filter(&Constraints::new(6)).is_empty());
+}
+
+#[test]
+fn test_index_letters_outside_alphabet() -> TestResult {
+    let index = WordIndex::new(WORDS, 5);
+
+    // Rejecting punctuation rules nothing out, requiring it rules everything out
+    let rejects = WordleQuery::new("*****", "о,'")?.constraints;
+    assert_eq!(index.filter(&rejects), vec!["баран", "вахта", "жираф", "кубик", "мачта", "парта", "сазан", "спуск", "шахта"]);
+
+    let mut required = Constraints::new(5);
+    required.required.push('x');
+    assert!(index.filter(&required).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_index_from_source() -> TestResult {
+    let memory = MemorySource::new(["шахта", "мачта", "кот"]);
+    let index = WordIndex::from_source(&memory, 5)?;
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.candidates(&WordleQuery::new("Ш****", "")?.constraints)?.len(), 1);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use rust_russian_wordle::layout::{fix_feedback, fix_guess_row, fix_layout, fix_rejects, is_mistyped, qwerty_to_jcuken, LayoutFix};
+use rust_russian_wordle::{parse_guess, process_rejects, Colour, WordleQuery, WordleQueryError};
+
+type TestResult = Result<(), WordleQueryError>;
+
+#[test]
+fn test_keys_map_to_jcuken_letters() {
+    assert_eq!(qwerty_to_jcuken('q'), 'й');
+    assert_eq!(qwerty_to_jcuken('['), 'х');
+    assert_eq!(qwerty_to_jcuken('.'), 'ю');
+    assert_eq!(qwerty_to_jcuken('F'), 'А');
+    assert_eq!(qwerty_to_jcuken(':'), 'Ж');
+    assert_eq!(qwerty_to_jcuken('*'), '*');
+    assert_eq!(qwerty_to_jcuken('/'), '/');
+}
+
+#[test]
+fn test_detects_the_wrong_layout() {
+    assert!(is_mistyped("cjkyw"));
+    assert!(is_mistyped(",fhfy"));
+    assert!(!is_mistyped("слово"));
+    // Latin look-alikes inside a Cyrillic word are not a layout mistake
+    assert!(!is_mistyped("слoвo"));
+    assert!(!is_mistyped("eoe"));
+    assert!(!is_mistyped("*****"));
+}
+
+#[test]
+fn test_fix_layout() {
+    assert_eq!(fix_layout("cjkyw"), "солнц");
+    assert_eq!(fix_layout(";bhfa"), "жираф");
+    assert_eq!(fix_layout("слово"), "слово");
+    assert_eq!(fix_layout("*J**F/j=2"), "*О**А/о=2");
+    assert_eq!(fix_rejects("c,p"), "с,з");
+    assert_eq!(fix_rejects("р,п"), "р,п");
+    assert_eq!(fix_rejects("e,o"), "e,o");
+}
+
+#[test]
+fn test_guesses_typed_on_the_wrong_layout() -> TestResult {
+    let (word, feedback) = parse_guess(&fix_guess_row(",fhfy:GYBBB"))?;
+    assert_eq!(word, "баран");
+    assert_eq!(feedback[0], Colour::Green);
+    assert_eq!(fix_guess_row("cjkyw"), "солнц");
+    Ok(())
+}
+
+#[test]
+fn test_feedback_typed_on_the_wrong_layout() -> TestResult {
+    // з/ж/с sit on the p, ; and c keys, which G/Y/B feedback never uses
+    assert_eq!(fix_feedback("p;ccp"), "зжссз");
+    assert_eq!(fix_feedback("GYBBB"), "GYBBB");
+    assert_eq!(fix_guess_row("cjkyw:p;ccp"), "солнц:зжссз");
+    assert_eq!(fix_guess_row("cjkyw:p;ccp/GBBBB/-"), "солнц:зжссз/GBBBB/-");
+
+    let (word, feedback) = parse_guess(&fix_guess_row("cjkyw p;ccp"))?;
+    assert_eq!(word, "солнц");
+    assert_eq!(feedback, [Colour::Green, Colour::Yellow, Colour::Grey, Colour::Grey, Colour::Green]);
+    Ok(())
+}
+
+#[test]
+fn test_comma_is_b_unless_it_separates_keys() {
+    assert_eq!(fix_rejects(","), "б");
+    assert_eq!(fix_rejects("c,p,"), "с,зб");
+    assert_eq!(fix_rejects(",c"), "бс");
+    assert_eq!(process_rejects(&fix_rejects(",")), vec!['б']);
+}
+
+#[test]
+fn test_patterns_and_rejects_typed_on_the_wrong_layout() -> TestResult {
+    assert_eq!(process_rejects(&fix_rejects("c,p")), vec!['с', 'з']);
+
+    let query = WordleQuery::new(&fix_layout("*J**F"), &fix_rejects("c,p"))?;
+    assert_eq!(query.constraints, WordleQuery::new("*О**А", "с,з")?.constraints);
+    Ok(())
+}
+
+#[test]
+fn test_layout_fix_can_be_switched_off() {
+    let off = LayoutFix::new(false);
+    assert_eq!(off.word("cjkyw"), "cjkyw");
+    assert_eq!(off.rejects("c,p"), "c,p");
+    assert_eq!(off.guess_row("cjkyw:GYBBB"), "cjkyw:GYBBB");
+
+    let on = LayoutFix::default();
+    assert_eq!(on.word("cjkyw"), "солнц");
+    assert_eq!(on.guess_row("cjkyw:GYBBB"), "солнц:GYBBB");
+}
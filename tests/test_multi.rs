@@ -0,0 +1,85 @@
+use rust_russian_wordle::multi::{parse_multi_guess, rank_multi_guesses, MultiBoard};
+use rust_russian_wordle::source::MemorySource;
+use rust_russian_wordle::{feedback, parse_feedback, WordleQueryError};
+
+mod common;
+use common::{words, WORDS};
+
+type TestResult = Result<(), WordleQueryError>;
+
+#[test]
+fn test_parse_multi_guess() -> TestResult {
+    let (word, feedback) = parse_multi_guess("СЛОВО:GYBBB/bbbbb/-", 3)?;
+    assert_eq!(word, "слово");
+    assert_eq!(feedback[0], Some(parse_feedback("GYBBB")?));
+    assert_eq!(feedback[1], Some(parse_feedback("BBBBB")?));
+    assert_eq!(feedback[2], None);
+
+    // The same separators as a single-board guess
+    assert_eq!(parse_multi_guess("слово GYBBB/bbbbb/-", 3)?, (word.clone(), feedback.clone()));
+    assert_eq!(parse_multi_guess("слово=GYBBB/bbbbb/-", 3)?, (word, feedback));
+    Ok(())
+}
+
+#[test]
+fn test_parse_multi_guess_errors() {
+    assert!(parse_multi_guess("слово:GYBBB/BBBBB", 4).is_err());
+    assert!(parse_multi_guess("слово", 1).is_err());
+    assert!(parse_multi_guess("слово:GYXBB", 1).is_err());
+}
+
+#[test]
+fn test_boards_keep_separate_constraints() -> TestResult {
+    let source = MemorySource::new(WORDS.iter().copied());
+    let mut boards = MultiBoard::new(2, 5);
+    boards.add_guess("слово", &[Some(feedback("слово", "шахта")?), Some(feedback("слово", "голос")?)])?;
+
+    let candidates = boards.candidates(&source)?;
+    assert!(candidates[0].contains(&"шахта".to_string()));
+    assert!(!candidates[0].contains(&"голос".to_string()));
+    assert_eq!(candidates[1], vec!["голос"]);
+    Ok(())
+}
+
+#[test]
+fn test_solved_board_needs_no_feedback() -> TestResult {
+    let mut boards = MultiBoard::new(2, 5);
+    boards.add_guess("шахта", &[Some(feedback("шахта", "шахта")?), Some(feedback("шахта", "слово")?)])?;
+    assert!(boards.is_solved(0));
+    assert!(!boards.all_solved());
+
+    boards.add_guess("слово", &[None, Some(feedback("слово", "слово")?)])?;
+    assert!(boards.all_solved());
+    Ok(())
+}
+
+#[test]
+fn test_rejected_row_leaves_boards_unchanged() -> TestResult {
+    let mut boards = MultiBoard::new(2, 5);
+    assert!(boards.add_guess("слово", &[Some(parse_feedback("BBBBB")?), None]).is_err());
+    assert!(boards.board(0).rejects.is_empty());
+    assert!(boards.add_guess("слово", &[Some(parse_feedback("BBBBB")?)]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_last_candidate_is_played_first() {
+    let candidates = vec![words(&["голос"]), words(&["шахта", "мачта", "вахта", "парта", "карта"])];
+    let rankings = rank_multi_guesses(&words(WORDS), &candidates);
+    assert_eq!(rankings[0].guess, "голос");
+    assert_eq!(rankings[0].solves, 1);
+}
+
+#[test]
+fn test_combined_information_sums_boards() {
+    let candidates = vec![words(&["шахта", "мачта"]), words(&["парта", "карта"]), Vec::new()];
+    let rankings = rank_multi_guesses(&words(&["шахта", "парта", "жираф"]), &candidates);
+
+    for ranking in &rankings {
+        assert_eq!(ranking.board_bits.len(), 3);
+        assert_eq!(ranking.board_bits[2], 0.0);
+        assert!((ranking.bits - ranking.board_bits.iter().sum::<f64>()).abs() < 1e-9);
+    }
+    // "жираф" cannot tell either pair apart
+    assert_eq!(rankings.last().map(|r| r.guess.as_str()), Some("жираф"));
+}
@@ -1,5 +1,5 @@
-use rusqlite::{Connection, Result};
-use rust_russian_wordle::{WordleQuery, process_rejects, WordleQueryError};
+use rusqlite::{params_from_iter, Connection, Result};
+use rust_russian_wordle::{WordleQuery, load_words_from_query, process_rejects, WordleQueryError};
 
 type TestResult = Result<(), WordleQueryError>;
 
@@ -23,12 +23,12 @@ fn test_query_excludes_uppercase_words() -> TestResult {
 
     // Build the query using WordleQuery
     let wordle_query = WordleQuery::new("*****", "")?;
-    let query = wordle_query.build_query();
+    let (query, params) = wordle_query.build_query();
     //println!("Generated Query: {}", query);
 
     // Execute the query
     let mut stmt = conn.prepare(&query)?;
-    let word_iter = stmt.query_map([], |row| {
+    let word_iter = stmt.query_map(params_from_iter(&params), |row| {
         let word: String = row.get(0)?;
         Ok(word)
     })?;
@@ -64,12 +64,12 @@ fn test_query_returns_only_5_letter_words() -> TestResult {
 
     // Build the query using WordleQuery
     let wordle_query = WordleQuery::new("*****", "")?;
-    let query = wordle_query.build_query();
+    let (query, params) = wordle_query.build_query();
     //println!("Generated Query: {}", query);
 
     // Execute the query
     let mut stmt = conn.prepare(&query)?;
-    let word_iter = stmt.query_map([], |row| {
+    let word_iter = stmt.query_map(params_from_iter(&params), |row| {
         let word: String = row.get(0)?;
         Ok(word)
     })?;
@@ -106,12 +106,12 @@ fn test_query_excludes_words_with_rejected_letters() -> TestResult {
 
     // Build the query using WordleQuery with rejected letters
     let wordle_query = WordleQuery::new("*****", "о,е")?;
-    let query = wordle_query.build_query();
+    let (query, params) = wordle_query.build_query();
     //println!("Generated Query: {}", query);
 
     // Execute the query
     let mut stmt = conn.prepare(&query)?;
-    let word_iter = stmt.query_map([], |row| {
+    let word_iter = stmt.query_map(params_from_iter(&params), |row| {
         let word: String = row.get(0)?;
         Ok(word)
     })?;
@@ -147,12 +147,12 @@ fn test_query_excludes_words_with_yellow_letters_in_correct_position() -> TestRe
 
     // Build the query using WordleQuery with a yellow letter 'н' not in the 3rd position
     let wordle_query = WordleQuery::new("**н**", "")?;
-    let query = wordle_query.build_query();
+    let (query, params) = wordle_query.build_query();
     //println!("Generated Query: {}", query);
 
     // Execute the query
     let mut stmt = conn.prepare(&query)?;
-    let word_iter = stmt.query_map([], |row| {
+    let word_iter = stmt.query_map(params_from_iter(&params), |row| {
         let word: String = row.get(0)?;
         Ok(word)
     })?;
@@ -195,12 +195,13 @@ fn test_query_with_limit() -> TestResult {
 
     // Build the query using WordleQuery with a limit of 10
     let wordle_query = WordleQuery::new("*****", "")?;
-    let query = format!("{} LIMIT 10", wordle_query.build_query());
+    let (query, params) = wordle_query.build_query();
+    let query = format!("{} LIMIT 10", query);
     //println!("Generated Query: {}", query);
 
     // Execute the query
     let mut stmt = conn.prepare(&query)?;
-    let word_iter = stmt.query_map([], |row| {
+    let word_iter = stmt.query_map(params_from_iter(&params), |row| {
         let word: String = row.get(0)?;
         Ok(word)
     })?;
@@ -262,3 +263,65 @@ fn test_wordle_query_invalid_pattern_just_too_long() -> Result<(), WordleQueryEr
     Ok(())
 }
 
+#[test]
+fn test_query_binds_letters_as_parameters() -> TestResult {
+    let wordle_query = WordleQuery::new("*****", "',%")?;
+    let (query, params) = wordle_query.build_query();
+
+    // Neither the quote nor the wildcard may leak into the SQL text
+    assert!(!query.contains("'''"));
+    assert!(!query.contains("%%%"));
+    assert_eq!(params, vec!["'".to_string(), "%".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn test_query_with_special_characters_runs() -> TestResult {
+    let conn = Connection::open_in_memory()?;
+    conn.execute("CREATE TABLE words (word TEXT NOT NULL)", [])?;
+    for word in ["мирно", "слово"] {
+        conn.execute("INSERT INTO words (word) VALUES (?1)", [&word])?;
+    }
+
+    // A stray "%" reject must not match every word, and "'" must not break the statement
+    let wordle_query = WordleQuery::new("*****", "%,'")?;
+    let (query, params) = wordle_query.build_query();
+    let mut results: Vec<String> = load_words_from_query(&query, &params, &conn)?.into_iter().collect();
+    results.sort();
+    assert_eq!(results, vec!["мирно", "слово"]);
+
+    // The same SQL is reused with different parameters for another pattern
+    let (other_query, other_params) = WordleQuery::new("*****", "о,'")?.build_query();
+    let (other_query_shape, _) = WordleQuery::new("*****", "и,%")?.build_query();
+    assert_eq!(other_query, other_query_shape);
+    let results = load_words_from_query(&other_query, &other_params, &conn)?;
+    assert!(results.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_query_with_six_letter_length() -> TestResult {
+    let conn = Connection::open_in_memory()?;
+    conn.execute("CREATE TABLE words (word TEXT NOT NULL)", [])?;
+    for word in ["молоко", "слово", "работа", "корова", "тест"] {
+        conn.execute("INSERT INTO words (word) VALUES (?1)", [&word])?;
+    }
+
+    let wordle_query = WordleQuery::with_length("*О***_т", "", 6)?;
+    assert_eq!(wordle_query.length(), 6);
+    let (query, params) = wordle_query.build_query();
+    let mut results: Vec<String> = load_words_from_query(&query, &params, &conn)?.into_iter().collect();
+    results.sort();
+
+    assert_eq!(results, vec!["корова", "молоко"]);
+    Ok(())
+}
+
+#[test]
+fn test_wordle_query_invalid_pattern_for_length() {
+    let result = WordleQuery::with_length("*****", "", 4);
+    match result.unwrap_err() {
+        WordleQueryError::QueryError(msg) => assert_eq!(msg, "Pattern must contain exactly 4 Cyrillic (or *) characters."),
+        _ => panic!("Unexpected error type"),
+    }
+}
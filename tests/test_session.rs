@@ -0,0 +1,52 @@
+use rust_russian_wordle::session::Session;
+use rust_russian_wordle::{parse_feedback, Constraints, WordleQuery, WordleQueryError};
+
+type TestResult = Result<(), WordleQueryError>;
+
+#[test]
+fn test_session_accumulates_and_undoes_guesses() -> TestResult {
+    let mut session = Session::new(Constraints::new(5));
+    session.add_guess("слово", &parse_feedback("BBBBB")?)?;
+    session.add_guess("карта", &parse_feedback("BGBGG")?)?;
+    assert_eq!(session.rows().len(), 2);
+    assert_eq!(session.constraints().greens[1], Some('а'));
+
+    let (guess, _) = session.undo().unwrap();
+    assert_eq!(guess, "карта");
+    assert_eq!(session.constraints().greens[1], None);
+    assert_eq!(session.constraints().rejects, vec!['с', 'л', 'о', 'в']);
+    Ok(())
+}
+
+#[test]
+fn test_session_reset_keeps_base_constraints() -> TestResult {
+    let base = WordleQuery::new("****А", "")?.constraints;
+    let mut session = Session::new(base.clone());
+    session.add_guess("слово", &parse_feedback("BBBBB")?)?;
+
+    session.reset();
+    assert!(session.rows().is_empty());
+    assert!(session.undo().is_none());
+    assert_eq!(session.constraints(), base);
+    Ok(())
+}
+
+#[test]
+fn test_session_rejects_bad_row_without_changing_state() -> TestResult {
+    let mut session = Session::new(Constraints::new(5));
+    assert!(session.add_guess("слов", &parse_feedback("BBBB")?).is_err());
+    assert!(session.rows().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_constraints_display() -> TestResult {
+    let mut constraints = Constraints::new(5);
+    constraints.add_guess("сосна", &parse_feedback("GYBBB")?)?;
+
+    let shown = constraints.to_string();
+    assert!(shown.contains("known:     С****"));
+    assert!(shown.contains("not at:    2: о; 3: с"));
+    assert!(shown.contains("с <= 1"));
+    Ok(())
+}
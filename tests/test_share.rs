@@ -0,0 +1,116 @@
+use rust_russian_wordle::share::{parse_share_grid, share_guesses, ShareCard, ShareRow};
+use rust_russian_wordle::source::{MemorySource, WordSource};
+use rust_russian_wordle::{parse_feedback, Colour, Constraints, WordleQueryError};
+
+mod common;
+use common::words;
+
+type TestResult = Result<(), WordleQueryError>;
+
+const PASTED: &str = "Wordle 412 3/6*\n\n⬜️⬜️⬜️⬜️⬜️\n⬜🟩⬜🟩🟩\n🟩🟩🟩🟩🟩\n";
+
+#[test]
+fn test_parse_share_grid_skips_header() -> TestResult {
+    let rows = parse_share_grid(PASTED)?;
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].colours, parse_feedback("BBBBB")?);
+    assert_eq!(rows[1].colours, parse_feedback("BGBGG")?);
+    assert!(rows.iter().all(|row| row.word.is_none()));
+    Ok(())
+}
+
+#[test]
+fn test_parse_share_grid_high_contrast_and_dark_tiles() -> TestResult {
+    let rows = parse_share_grid("🟧🟦⬛⬛🟧")?;
+    assert_eq!(rows[0].colours, parse_feedback("GYBBG")?);
+    Ok(())
+}
+
+#[test]
+fn test_parse_share_grid_with_words() -> TestResult {
+    let rows = parse_share_grid("⬜⬜⬜⬜⬜ СЛОВО\n⬜🟩⬜🟩🟩 карта")?;
+    assert_eq!(rows[0], ShareRow { colours: parse_feedback("BBBBB")?, word: Some("слово".to_string()) });
+    assert_eq!(rows[1].word.as_deref(), Some("карта"));
+    Ok(())
+}
+
+#[test]
+fn test_parse_share_grid_errors() {
+    assert!(parse_share_grid("Wordle 412 X/6").is_err());
+    assert!(parse_share_grid("⬜⬜⬜⬜⬜\n⬜🟩⬜🟩").is_err());
+    assert!(parse_share_grid("⬜⬜ ⬜⬜⬜").is_err());
+}
+
+#[test]
+fn test_share_guesses_pairs_rows_with_words() -> TestResult {
+    let rows = parse_share_grid("⬜⬜⬜⬜⬜ слово\n⬜🟩⬜🟩🟩")?;
+    let guesses = share_guesses(&rows, &words(&["КАРТА"]))?;
+    assert_eq!(guesses[0].0, "слово");
+    assert_eq!(guesses[1], ("карта".to_string(), parse_feedback("BGBGG")?));
+    Ok(())
+}
+
+#[test]
+fn test_share_guesses_word_count_mismatch() -> TestResult {
+    let rows = parse_share_grid(PASTED)?;
+    assert!(share_guesses(&rows, &words(&["слово", "карта"])).is_err());
+    assert!(share_guesses(&rows, &words(&["слово", "карта", "шахта", "мачта"])).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_share_grid_narrows_candidates() -> TestResult {
+    let rows = parse_share_grid("Wordle 412\n⬜⬜⬜⬜⬜\n⬜🟩⬜🟩🟩")?;
+    let mut constraints = Constraints::new(5);
+    for (word, colours) in share_guesses(&rows, &words(&["слово", "карта"]))? {
+        constraints.add_guess(&word, &colours)?;
+    }
+
+    let source = MemorySource::new(["шахта", "рамка", "парта", "вахта", "мачта"]);
+    let mut candidates: Vec<String> = source.candidates(&constraints)?.into_iter().collect();
+    candidates.sort();
+    assert_eq!(candidates, vec!["мачта", "шахта"]);
+    Ok(())
+}
+
+fn rows(feedback: &[&str]) -> Result<Vec<Vec<Colour>>, WordleQueryError> {
+    feedback.iter().map(|row| parse_feedback(row)).collect()
+}
+
+#[test]
+fn test_share_text() -> TestResult {
+    let card = ShareCard::new(rows(&["BBBBB", "BGBGG", "GGGGG"])?, 6).with_puzzle(Some(412));
+    assert_eq!(card.text(), "Wordle 412 3/6\n\n⬜⬜⬜⬜⬜\n⬜🟩⬜🟩🟩\n🟩🟩🟩🟩🟩");
+    Ok(())
+}
+
+#[test]
+fn test_share_text_hard_mode_and_failure() -> TestResult {
+    let card = ShareCard::new(rows(&["BBBBB", "BYBBB"])?, 2).with_hard_mode(true);
+    assert!(!card.solved());
+    assert_eq!(card.score(), "X/2*");
+    assert!(card.text().starts_with("Wordle X/2*\n"));
+    Ok(())
+}
+
+#[test]
+fn test_share_text_round_trips_through_parser() -> TestResult {
+    let card = ShareCard::new(rows(&["BYBBB", "YGBBG", "GGGGG"])?, 6).with_puzzle(Some(9)).with_hard_mode(true);
+    let parsed: Vec<_> = parse_share_grid(&card.text())?.into_iter().map(|row| row.colours).collect();
+    assert_eq!(parsed, card.rows);
+    Ok(())
+}
+
+#[test]
+fn test_spoiler_free_summary() -> TestResult {
+    let solved = ShareCard::new(rows(&["BBBBB", "GGGGG"])?, 6).with_puzzle(Some(412)).with_hard_mode(true);
+    assert_eq!(solved.summary(), "Wordle 412: solved in 2 of 6 guesses in hard mode.");
+
+    let lost = ShareCard::new(rows(&["BBBBB"])?, 1);
+    assert_eq!(lost.summary(), "Wordle: not solved in 1 guess.");
+    assert!(!lost.summary().contains('⬜'));
+
+    let first_try = ShareCard::new(rows(&["GGGGG"])?, 1);
+    assert_eq!(first_try.summary(), "Wordle: solved in 1 of 1 guess.");
+    Ok(())
+}
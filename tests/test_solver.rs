@@ -0,0 +1,86 @@
+use rust_russian_wordle::solver::{feedback_code, rank_by_information, rank_guesses, GuessRanking};
+use rust_russian_wordle::scoring::{FrequencyProduct, Scorer};
+use rust_russian_wordle::Strategy;
+
+mod common;
+use common::words;
+
+#[test]
+fn test_feedback_code_green_uses_up_letter() {
+    // "шахта" has a single "т", which is used up by the green, so every other "т" is grey
+    let guess: Vec<char> = "ттттт".chars().collect();
+    let answer: Vec<char> = "шахта".chars().collect();
+    assert_eq!(feedback_code(&guess, &answer), 2 * 3);
+}
+
+#[test]
+fn test_rank_by_information_prefers_splitting_guess() {
+    let candidates = words(&["мачта", "шахта", "вахта", "пахта"]);
+    // "вымпш" separates all four candidates, while "мачта" leaves three of them together
+    let guesses = words(&["мачта", "вымпш"]);
+
+    let rankings = rank_by_information(&guesses, &candidates);
+    assert_eq!(rankings[0].guess, "вымпш");
+    assert_eq!(rankings[0].bits, 2.0);
+    assert!(!rankings[0].is_candidate);
+    assert!(rankings[1].is_candidate);
+}
+
+#[test]
+fn test_rank_by_information_breaks_ties_with_candidates() {
+    let candidates = words(&["мачта", "шахта"]);
+    let guesses = words(&["шмель", "шахта"]);
+
+    let rankings = rank_by_information(&guesses, &candidates);
+    assert_eq!(rankings[0].guess, "шахта");
+    assert_eq!(rankings[0].bits, rankings[1].bits);
+}
+
+#[test]
+fn test_guess_ranking_partition_statistics() {
+    let candidates: Vec<Vec<char>> = ["мачта", "шахта", "вахта", "пахта"]
+        .iter()
+        .map(|w| w.chars().collect())
+        .collect();
+
+    // "мачта" is green for itself and leaves the other three in one group
+    let ranking = GuessRanking::evaluate("мачта", &candidates, true);
+    assert_eq!(ranking.worst_case, 3);
+    assert_eq!(ranking.expected_remaining, (1.0 + 9.0) / 4.0);
+}
+
+#[test]
+fn test_minimax_and_expected_orderings() {
+    let candidates = words(&["мачта", "шахта", "вахта", "пахта", "палка", "балка", "галка", "банка"]);
+    let guesses = words(&["мачта", "вымпш", "балка", "пробы", "шпага"]);
+
+    let minimax = rank_guesses(Strategy::Minimax, &FrequencyProduct::default(), &guesses, &candidates);
+    assert!(minimax.windows(2).all(|w| w[0].worst_case <= w[1].worst_case));
+
+    let expected = rank_guesses(Strategy::ExpectedRemaining, &FrequencyProduct::default(), &guesses, &candidates);
+    assert!(expected.windows(2).all(|w| w[0].expected_remaining <= w[1].expected_remaining));
+}
+
+#[test]
+fn test_strategy_from_str() {
+    assert_eq!("minimax".parse::<Strategy>().unwrap(), Strategy::Minimax);
+    assert_eq!("expected".parse::<Strategy>().unwrap(), Strategy::ExpectedRemaining);
+    assert!("random".parse::<Strategy>().is_err());
+}
+
+// A scorer defined outside the library, as a downstream user would write one
+struct PreferLetter(char);
+
+impl Scorer for PreferLetter {
+    fn score(&self, word: &str) -> f64 {
+        word.chars().filter(|&c| c == self.0).count() as f64
+    }
+}
+
+#[test]
+fn test_frequency_strategy_uses_custom_scorer() {
+    let candidates = words(&["мачта", "шахта", "вахта"]);
+    let rankings = rank_guesses(Strategy::Frequency, &PreferLetter('ш'), &candidates, &candidates);
+    assert_eq!(rankings[0].guess, "шахта");
+    assert_eq!(rankings[0].score, 1.0);
+}
@@ -0,0 +1,88 @@
+use rusqlite::Connection;
+use rust_russian_wordle::source::{FileSource, MemorySource, SqliteSource, WordSource};
+use rust_russian_wordle::{WordleQuery, WordleQueryError};
+use std::collections::HashSet;
+use std::fs;
+
+mod common;
+use common::sample_constraints;
+
+type TestResult = Result<(), WordleQueryError>;
+
+const WORDS: &[&str] = &[
+    "слово", "сосна", "стоик", "сокол", "голос", "молот", "шахта", "мачта", "вахта", "парта", "жираф", "баран",
+    "кто-то", "Мачта", "ооооо",
+];
+
+fn sqlite_source(words: &[&str]) -> Result<SqliteSource, WordleQueryError> {
+    let conn = Connection::open_in_memory()?;
+    conn.execute("CREATE TABLE words (word TEXT NOT NULL)", [])?;
+    for word in words {
+        conn.execute("INSERT INTO words (word) VALUES (?1)", [word])?;
+    }
+    Ok(SqliteSource::new(conn))
+}
+
+fn sorted(words: HashSet<String>) -> Vec<String> {
+    let mut words: Vec<String> = words.into_iter().collect();
+    words.sort();
+    words
+}
+
+#[test]
+fn test_memory_source_agrees_with_sqlite() -> TestResult {
+    let sqlite = sqlite_source(WORDS)?;
+    let memory = MemorySource::new(WORDS.iter().copied());
+
+    for constraints in sample_constraints()? {
+        assert_eq!(
+            sorted(memory.candidates(&constraints)?),
+            sorted(sqlite.candidates(&constraints)?),
+            "constraints:\n{}",
+            constraints
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_memory_source_filters() -> TestResult {
+    let memory = MemorySource::new(WORDS.iter().copied());
+    let constraints = WordleQuery::new("****А", "р,п")?.constraints;
+    assert_eq!(sorted(memory.candidates(&constraints)?), vec!["вахта", "мачта", "сосна", "шахта"]);
+
+    // Hyphenated and capitalised words never match, as in the SQL query
+    let all = memory.words_of_length(5)?;
+    assert!(!all.contains("Мачта"));
+    assert!(!all.contains("кто-то"));
+    Ok(())
+}
+
+#[test]
+fn test_file_source_reads_word_list() -> TestResult {
+    let path = std::env::temp_dir().join(format!("ruwordle_source_{}.txt", std::process::id()));
+    fs::write(&path, "# test list\nШахта\nмачта\nмачта\nёжики\nword\n")?;
+
+    let source = FileSource::open(&path)?;
+    fs::remove_file(&path)?;
+
+    assert_eq!(source.len(), 3);
+    assert_eq!(sorted(source.words_of_length(5)?), vec!["ежики", "мачта", "шахта"]);
+    let constraints = WordleQuery::new("Ш****", "")?.constraints;
+    assert_eq!(sorted(source.candidates(&constraints)?), vec!["шахта"]);
+    Ok(())
+}
+
+#[test]
+fn test_file_source_missing_file() {
+    assert!(FileSource::open("/nonexistent/words.txt").is_err());
+}
+
+#[cfg(feature = "embedded-words")]
+#[test]
+fn test_embedded_memory_source() -> TestResult {
+    let source = MemorySource::embedded()?;
+    assert!(source.len() > 1000);
+    assert_eq!(source.words_of_length(5)?.len(), source.len());
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use rust_russian_wordle::{Wordle};
 use rust_russian_wordle::convert_latin_to_cyrillic;
+use rust_russian_wordle::guess_count;
 
 #[test]
 fn test_replace_yo() {
@@ -34,6 +35,16 @@ fn test_convert_latin_to_cyrillic() {
     assert_eq!(convert_latin_to_cyrillic('я'), 'я'); // Cyrillic character
 }
 
+#[test]
+fn test_calculate_score_continues_past_unknown_letters() {
+    let ru_letter_freqs = Wordle::init_letter_freqs();
+    // An unknown character no longer stops the product early, it pulls the score down
+    let with_unknown = Wordle::calculate_score("со1ет", &ru_letter_freqs);
+    let known = Wordle::calculate_score("сонет", &ru_letter_freqs);
+    assert!(with_unknown < known);
+    assert!(with_unknown > 0.0);
+}
+
 #[cfg(test)]
 mod tests {
     use rust_russian_wordle::WordleQuery;
@@ -46,4 +57,11 @@ mod tests {
         assert_eq!(input, "****"); // Check if the string was modified as expected
         assert_eq!(collected_letters, vec!['н']); // Check if the Cyrillic letter was collected
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_guess_count() {
+    assert_eq!(guess_count(1), "1 guess");
+    assert_eq!(guess_count(3), "3 guesses");
+    assert_eq!(guess_count(0), "0 guesses");
+}